@@ -0,0 +1,173 @@
+//! Derive macros for [leptos_twelements](https://docs.rs/leptos_twelements).
+//! Use them through the re-exports in `leptos_twelements`, not from this crate directly.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Derive `SelectOption` for an enum whose variants don't have fields, so it can be used in a `Select`.
+///
+/// By default, the name of a variant is used both as its value and as its label.
+/// This can be changed with the `#[select_option(...)]` attribute on the variant:
+/// - `value = "..."`: The value of the option. Must not be empty.
+/// - `label = "..."`: The text shown for the option.
+/// - `group = "..."`: The group the option is shown in.
+/// - `disabled`: The option is shown but can't be selected.
+///
+/// Since the values of a `Select` must be unique, it is a compile error if two variants have the same value.
+///
+/// Example
+/// -------
+/// ```ignore
+/// #[derive(Clone, SelectOption)]
+/// enum Country {
+///     #[select_option(value = "de", label = "Germany", group = "Europe")]
+///     Germany,
+///     #[select_option(value = "fr", label = "France", group = "Europe")]
+///     France,
+///     #[select_option(value = "us", label = "United States", group = "America", disabled)]
+///     UnitedStates,
+/// }
+/// ```
+#[proc_macro_derive(SelectOption, attributes(select_option))]
+pub fn derive_select_option(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match select_option_impl(input) {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+struct VariantOptions {
+    value: String,
+    label: String,
+    group: Option<String>,
+    disabled: bool,
+}
+
+fn select_option_impl(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "SelectOption can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::new();
+    let mut seen_values: Vec<(String, Span)> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "SelectOption can only be derived for enums whose variants don't have fields",
+            ));
+        }
+        let ident = &variant.ident;
+        let mut options = VariantOptions {
+            value: ident.to_string(),
+            label: ident.to_string(),
+            group: None,
+            disabled: false,
+        };
+        let mut value_span = ident.span();
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("select_option"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("value") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    if value.value().is_empty() {
+                        return Err(Error::new_spanned(
+                            &value,
+                            "The value of a SelectOption can't be empty",
+                        ));
+                    }
+                    options.value = value.value();
+                    value_span = value.span();
+                    Ok(())
+                } else if meta.path.is_ident("label") {
+                    options.label = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("group") {
+                    options.group = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("disabled") {
+                    options.disabled = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Expected `value`, `label`, `group` or `disabled`"))
+                }
+            })?;
+        }
+        if let Some((_, first_span)) = seen_values
+            .iter()
+            .find(|(value, _)| *value == options.value)
+        {
+            let mut error = Error::new(
+                value_span,
+                format!("Duplicate SelectOption value {:?}", options.value),
+            );
+            error.combine(Error::new(*first_span, "First used here"));
+            return Err(error);
+        }
+        seen_values.push((options.value.clone(), value_span));
+        variants.push((ident, options));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let value_arms = variants.iter().map(|(ident, options)| {
+        let value = &options.value;
+        quote! { Self::#ident => #value }
+    });
+    let label_arms = variants.iter().map(|(ident, options)| {
+        let label = &options.label;
+        quote! { Self::#ident => #label }
+    });
+    let group_arms = variants
+        .iter()
+        .map(|(ident, options)| match &options.group {
+            Some(group) => quote! { Self::#ident => ::std::option::Option::Some(#group) },
+            None => quote! { Self::#ident => ::std::option::Option::None },
+        });
+    let disabled_arms = variants.iter().map(|(ident, options)| {
+        let disabled = options.disabled;
+        quote! { Self::#ident => #disabled }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::leptos_twelements::components::forms::SelectOption for #name #ty_generics #where_clause {
+            fn value(&self) -> ::std::string::String {
+                let value: &'static str = match self {
+                    #(#value_arms,)*
+                };
+                ::std::string::ToString::to_string(value)
+            }
+
+            fn view(&self) -> ::leptos::View {
+                let label: &'static str = match self {
+                    #(#label_arms,)*
+                };
+                ::leptos::IntoView::into_view(label)
+            }
+
+            fn group(&self) -> ::std::option::Option<::std::string::String> {
+                let group: ::std::option::Option<&'static str> = match self {
+                    #(#group_arms,)*
+                };
+                group.map(::std::string::ToString::to_string)
+            }
+
+            fn disabled(&self) -> bool {
+                match self {
+                    #(#disabled_arms,)*
+                }
+            }
+        }
+    })
+}
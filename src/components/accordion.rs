@@ -0,0 +1,123 @@
+use leptos::*;
+use std::collections::HashSet;
+
+use super::Collapse;
+
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    open_items: RwSignal<HashSet<usize>>,
+    next_id: StoredValue<usize>,
+    always_open: bool,
+    flush: bool,
+}
+
+impl AccordionContext {
+    fn toggle(&self, id: usize) {
+        let always_open = self.always_open;
+        self.open_items.update(|open_items| {
+            if !open_items.remove(&id) {
+                if !always_open {
+                    open_items.clear();
+                }
+                open_items.insert(id);
+            }
+        });
+    }
+}
+
+/// An Accordion component. Add [AccordionItem]s as children.
+///
+/// See [Tailwind Elements: Accordion](https://tailwind-elements.com/docs/standard/components/accordion/)
+#[component]
+pub fn Accordion(
+    /// If `false`, opening an item closes all other items. If `true`, any number of items can be open at the same time.
+    #[prop(default = false)]
+    always_open: bool,
+    /// Whether to remove the borders and rounded corners so the accordion can be placed edge-to-edge with its parent container.
+    ///
+    /// See [Tailwind Elements: Accordion#Flush](https://tailwind-elements.com/docs/standard/components/accordion/#flush)
+    #[prop(default = false)]
+    flush: bool,
+    /// The [AccordionItem]s
+    children: Children,
+) -> impl IntoView {
+    provide_context(AccordionContext {
+        open_items: create_rw_signal(HashSet::new()),
+        next_id: store_value(0),
+        always_open,
+        flush,
+    });
+
+    view! {
+        <div>
+            {children()}
+        </div>
+    }
+}
+
+/// An item in an [Accordion].
+#[component]
+pub fn AccordionItem(
+    /// The title shown in the header of the item.
+    #[prop(into)]
+    title: MaybeSignal<String>,
+    /// Whether the item is open when it is first rendered.
+    #[prop(default = false)]
+    initially_open: bool,
+    /// Content shown when the item is open
+    children: Children,
+) -> impl IntoView {
+    let accordion =
+        use_context::<AccordionContext>().expect("AccordionItem must be used inside an Accordion");
+    let id = accordion.next_id.get_value();
+    accordion.next_id.set_value(id + 1);
+    if initially_open {
+        accordion.toggle(id);
+    }
+    let open = Signal::derive(move || {
+        accordion
+            .open_items
+            .with(|open_items| open_items.contains(&id))
+    });
+
+    let item_class = if accordion.flush {
+        "border border-l-0 border-r-0 border-t-0 rounded-none border-neutral-200 bg-white last:border-b-0 dark:border-neutral-600 dark:bg-neutral-800"
+    } else {
+        "overflow-hidden border border-neutral-200 bg-white first:rounded-t-lg last:rounded-b-lg [&:not(:first-child)]:border-t-0 dark:border-neutral-600 dark:bg-neutral-800"
+    };
+
+    view! {
+        <div class=item_class>
+            <h2 class="mb-0">
+                <button
+                    class="group relative flex w-full items-center rounded-none border-0 bg-white px-5 py-4 text-left text-base text-neutral-800 transition [overflow-anchor:none] hover:z-[2] focus:z-[3] focus:outline-none dark:bg-neutral-800 dark:text-white [&:not([data-te-collapse-collapsed])]:bg-white [&:not([data-te-collapse-collapsed])]:text-primary [&:not([data-te-collapse-collapsed])]:[box-shadow:inset_0_-1px_0_rgba(229,231,235)] dark:[&:not([data-te-collapse-collapsed])]:bg-neutral-800 dark:[&:not([data-te-collapse-collapsed])]:text-primary-400 dark:[&:not([data-te-collapse-collapsed])]:[box-shadow:inset_0_-1px_0_rgba(75,85,99)]"
+                    type="button"
+                    data-te-collapse-collapsed=move || !open()
+                    aria-expanded=move || if open() { "true" } else { "false" }
+                    on:click=move |_| accordion.toggle(id)
+                >
+                    {title}
+                    <span class="ml-auto h-5 w-5 shrink-0 rotate-[-180deg] fill-[#336dec] transition-transform duration-200 ease-in-out group-[[data-te-collapse-collapsed]]:rotate-0 group-[[data-te-collapse-collapsed]]:fill-[#212529] motion-reduce:transition-none dark:fill-blue-300 dark:group-[[data-te-collapse-collapsed]]:fill-white">
+                        <svg
+                            xmlns="http://www.w3.org/2000/svg"
+                            fill="none"
+                            viewBox="0 0 24 24"
+                            stroke-width="1.5"
+                            stroke="currentColor"
+                            class="h-6 w-6">
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                d="M19.5 8.25l-7.5 7.5-7.5-7.5" />
+                        </svg>
+                    </span>
+                </button>
+            </h2>
+            <Collapse open=open>
+                <div class="px-5 py-4">
+                    {children()}
+                </div>
+            </Collapse>
+        </div>
+    }
+}
@@ -24,7 +24,7 @@ pub enum AlertType {
 }
 
 impl AlertType {
-    fn class(&self) -> &'static str {
+    pub(crate) fn class(&self) -> &'static str {
         match self {
             Self::Primary => "bg-primary-100 text-primary-600",
             Self::Secondary => "bg-secondary-100 text-secondary-800",
@@ -74,7 +74,7 @@ pub enum ButtonStyle {
 }
 
 impl ButtonStyle {
-    const fn classes(self) -> &'static str {
+    pub(crate) const fn classes(self) -> &'static str {
         match self {
             Self::Primary => "inline-block bg-primary px-6 pb-2 pt-2.5 text-xs font-medium uppercase leading-normal text-white shadow-[0_4px_9px_-4px_#3b71ca] transition duration-150 ease-in-out hover:bg-primary-600 hover:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] focus:bg-primary-600 focus:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] focus:outline-none focus:ring-0 active:bg-primary-700 active:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] dark:shadow-[0_4px_9px_-4px_rgba(59,113,202,0.5)] dark:hover:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)] dark:focus:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)] dark:active:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)]",
             Self::Secondary => "inline-block bg-primary-100 px-6 pb-2 pt-2.5 text-xs font-medium uppercase leading-normal text-primary-700 transition duration-150 ease-in-out hover:bg-primary-accent-100 focus:bg-primary-accent-100 focus:outline-none focus:ring-0 active:bg-primary-accent-200",
@@ -112,6 +112,9 @@ pub fn Button(
     /// Whether to add a ripple effect to the button.
     #[prop(into, default = None.into())]
     ripple: MaybeSignal<Option<Ripple>>,
+    /// Whether the button submits the form it is in, e.g. a [Form](crate::components::forms::Form).
+    #[prop(default = false)]
+    submit: bool,
     /// Elements displayed in the button
     children: Children,
 ) -> impl IntoView {
@@ -142,7 +145,7 @@ pub fn Button(
     view! {
         <button
             ref=button_ref
-            type="button"
+            type=if submit { "submit" } else { "button" }
             class=classes
             disabled=disabled
         >
@@ -0,0 +1,296 @@
+use leptos::{ev::KeyboardEvent, *};
+use std::rc::Rc;
+
+// Tailwind Elements' ChipsInput creates and removes the chip elements itself, which doesn't play well with
+// leptos rendering them from a signal. So we render the chips ourselves and only reuse the Tailwind Elements styling.
+
+/// A Chip component, i.e. a small tag showing a text and optionally an avatar image and a close icon.
+///
+/// See [Tailwind Elements: Chips](https://tailwind-elements.com/docs/standard/components/chips/)
+#[component]
+pub fn Chip(
+    /// Whether the chip shows a close icon.
+    /// If no `on_close` callback is given, clicking the close icon hides the chip.
+    #[prop(into, default = false.into())]
+    closable: MaybeSignal<bool>,
+    /// Callback that is called when the user clicks the close icon.
+    /// If it is given, the chip doesn't hide itself and the callback is expected to remove it.
+    #[prop(into, optional)]
+    on_close: Option<Callback<()>>,
+    /// URL of an avatar image shown at the start of the chip.
+    #[prop(into, optional)]
+    avatar: Option<MaybeSignal<String>>,
+    /// Alternative text for the avatar image.
+    #[prop(into, default = "".into())]
+    avatar_alt: MaybeSignal<String>,
+    /// The content of the chip, usually its text.
+    children: Children,
+) -> impl IntoView {
+    let (closed, set_closed) = create_signal(false);
+    let on_close_click = move |_| match &on_close {
+        Some(on_close) => on_close(()),
+        None => set_closed(true),
+    };
+
+    view! {
+        <div
+            class="my-[5px] mr-4 flex h-[32px] cursor-pointer items-center justify-between rounded-[16px] bg-[#eceff1] px-[12px] py-0 text-[13px] font-normal normal-case leading-loose text-[#4f4f4f] shadow-none transition-[opacity] duration-300 ease-linear hover:!shadow-none active:bg-[#cacfd1] dark:bg-neutral-600 dark:text-neutral-200"
+            class:hidden=closed
+        >
+            {avatar.map(|avatar| view! {
+                <img
+                    class="my-0 -ml-[12px] mr-[8px] h-[inherit] w-[inherit] rounded-[100%]"
+                    src=avatar
+                    alt=avatar_alt
+                />
+            })}
+            {children()}
+            <Show when=closable fallback=|| ()>
+                <span
+                    class="float-right w-4 cursor-pointer pl-[8px] text-[16px] opacity-[.53] transition-all duration-200 ease-in-out hover:text-[#8b8b8b] dark:text-neutral-400 dark:hover:text-neutral-100"
+                    role="button"
+                    aria-label="Remove"
+                    on:click=on_close_click.clone()
+                >
+                    <CloseIcon />
+                </span>
+            </Show>
+        </div>
+    }
+}
+
+/// A ChipsInput component, i.e. an input field for an editable list of tags.
+///
+/// The user adds a tag by typing it and pressing enter, or by picking one of the `suggestions`.
+/// Tags are removed with their close icon, or by pressing backspace in the empty input field.
+/// Empty and duplicate tags are ignored.
+///
+/// See [Tailwind Elements: Chips](https://tailwind-elements.com/docs/standard/components/chips/)
+#[component]
+pub fn ChipsInput<OnChangeFn: Fn(Vec<String>) + 'static>(
+    /// This signal is read to show the current list of tags.
+    /// Whenever this signal changes, the chips are updated.
+    /// But note that the tags can also be changed by the user and if the `on_change` function doesn't
+    /// update the `value` signal, the chips input can get out of sync with the signal.
+    #[prop(into)]
+    value: MaybeSignal<Vec<String>>,
+    /// Callback that is called with the new list of tags when the user adds or removes a tag.
+    on_change: OnChangeFn,
+    /// Tags that are suggested in a dropdown while the user is typing.
+    /// Only suggestions containing the typed text and not already added are shown.
+    #[prop(into, default = vec![].into())]
+    suggestions: MaybeSignal<Vec<String>>,
+    /// The maximum number of tags. Once it is reached, no more tags can be added, but existing tags can still be removed.
+    #[prop(into, default = None.into())]
+    max: MaybeSignal<Option<usize>>,
+    /// Whether the chips input is disabled.
+    #[prop(into, default = false.into())]
+    disabled: MaybeSignal<bool>,
+    /// The id of the input field. Useful to associate a label with it.
+    #[prop(into, default = "".into())]
+    id: String,
+    /// This label is shown above the chips.
+    #[prop(into, default = "".into())]
+    label: MaybeSignal<String>,
+    /// This placeholder is shown in the input field while it is empty.
+    #[prop(into, default = "".into())]
+    placeholder: MaybeSignal<String>,
+) -> impl IntoView {
+    let value = Signal::derive(move || value.get());
+    let suggestions = Signal::derive(move || suggestions.get());
+    let on_change = Rc::new(on_change);
+
+    let (text, set_text) = create_signal(String::new());
+    let (focused, set_focused) = create_signal(false);
+    // Index into `matching_suggestions` of the suggestion highlighted with the arrow keys
+    let (highlighted, set_highlighted) = create_signal(None::<usize>);
+
+    let full = move || max().is_some_and(|max| value.with(Vec::len) >= max);
+    let matching_suggestions = create_memo(move |_| {
+        let text = text().trim().to_lowercase();
+        value.with(|value| {
+            suggestions.with(|suggestions| {
+                suggestions
+                    .iter()
+                    .filter(|suggestion| !value.contains(suggestion))
+                    .filter(|suggestion| suggestion.to_lowercase().contains(&text))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+    let show_suggestions = move || {
+        focused() && !full() && matching_suggestions.with(|suggestions| !suggestions.is_empty())
+    };
+
+    let add = {
+        let on_change = Rc::clone(&on_change);
+        Rc::new(move |tag: String| {
+            let tag = tag.trim().to_string();
+            set_text(String::new());
+            set_highlighted(None);
+            if tag.is_empty() || full() || value.with_untracked(|value| value.contains(&tag)) {
+                return;
+            }
+            let mut new_value = value.get_untracked();
+            new_value.push(tag);
+            on_change(new_value);
+        })
+    };
+    let remove = {
+        let on_change = Rc::clone(&on_change);
+        Rc::new(move |index: usize| {
+            let mut new_value = value.get_untracked();
+            if index < new_value.len() {
+                new_value.remove(index);
+                on_change(new_value);
+            }
+        })
+    };
+
+    let on_keydown = {
+        let add = Rc::clone(&add);
+        let remove = Rc::clone(&remove);
+        move |ev: KeyboardEvent| {
+            let num_suggestions = matching_suggestions.with_untracked(Vec::len);
+            match ev.key().as_str() {
+                "Enter" => {
+                    ev.prevent_default();
+                    // Keep the entered text, so it can still be added after removing another tag
+                    if full() {
+                        return;
+                    }
+                    let suggestion = highlighted.get_untracked().and_then(|index| {
+                        matching_suggestions
+                            .with_untracked(|suggestions| suggestions.get(index).cloned())
+                    });
+                    add(suggestion.unwrap_or_else(|| text.get_untracked()));
+                }
+                "ArrowDown" if num_suggestions > 0 => {
+                    ev.prevent_default();
+                    set_highlighted(Some(match highlighted.get_untracked() {
+                        Some(index) => (index + 1) % num_suggestions,
+                        None => 0,
+                    }));
+                }
+                "ArrowUp" if num_suggestions > 0 => {
+                    ev.prevent_default();
+                    set_highlighted(Some(match highlighted.get_untracked() {
+                        Some(0) | None => num_suggestions - 1,
+                        Some(index) => index - 1,
+                    }));
+                }
+                "Escape" => set_highlighted(None),
+                "Backspace" if text.with_untracked(String::is_empty) => {
+                    let len = value.with_untracked(Vec::len);
+                    if len > 0 {
+                        remove(len - 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let id = if id.is_empty() { None } else { Some(id) };
+    let label = Signal::derive(move || label.get());
+    let label_for = id.clone();
+
+    view! {
+        <div class="relative mb-3">
+            <Show when=move || label.with(|label| !label.is_empty()) fallback=|| ()>
+                <label for=label_for.clone() class="mb-2 inline-block text-neutral-700 dark:text-neutral-200">
+                    {label}
+                </label>
+            </Show>
+            <div class="relative flex min-h-[42px] flex-wrap items-center rounded border border-solid border-neutral-300 px-[5px] transition-all duration-300 ease-[cubic-bezier(0.25,0.1,0.25,1)] focus-within:border-primary dark:border-neutral-600 dark:focus-within:border-primary">
+                {move || {
+                    let remove = Rc::clone(&remove);
+                    value()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, tag)| {
+                            let remove = Rc::clone(&remove);
+                            view! {
+                                <Chip
+                                    closable=Signal::derive(move || !disabled())
+                                    on_close=Callback::new(move |()| remove(index))
+                                >
+                                    {tag}
+                                </Chip>
+                            }
+                        })
+                        .collect_view()
+                }}
+                <input
+                    type="text"
+                    class="peer block min-h-[auto] w-[150px] flex-grow rounded border-0 bg-transparent px-3 py-[0.32rem] leading-[1.6] outline-none transition-all duration-200 ease-linear motion-reduce:transition-none dark:text-neutral-200 dark:placeholder:text-neutral-200"
+                    id=id
+                    placeholder=placeholder
+                    aria-label=label
+                    autocomplete="off"
+                    // Not disabled when full, so that tags can still be removed with backspace
+                    disabled=disabled
+                    prop:value=text
+                    on:input=move |ev| {
+                        set_text(event_target_value(&ev));
+                        set_highlighted(None);
+                    }
+                    on:keydown=on_keydown
+                    on:focus=move |_| set_focused(true)
+                    on:blur=move |_| {
+                        set_focused(false);
+                        set_highlighted(None);
+                    }
+                />
+            </div>
+            <Show when=show_suggestions fallback=|| ()>
+                <ul class="absolute z-[1000] mt-1 max-h-60 w-full list-none overflow-y-auto rounded-lg border-none bg-white bg-clip-padding text-left text-base shadow-lg dark:bg-neutral-700">
+                    {
+                        let add = Rc::clone(&add);
+                        move || {
+                            let add = Rc::clone(&add);
+                            matching_suggestions()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, suggestion)| {
+                                    let add = Rc::clone(&add);
+                                    let tag = suggestion.clone();
+                                    view! {
+                                        <li
+                                            class="block w-full cursor-pointer whitespace-nowrap px-4 py-2 text-sm font-normal text-neutral-700 hover:bg-neutral-100 dark:text-neutral-200 dark:hover:bg-white/30"
+                                            class=("bg-neutral-100", move || highlighted() == Some(index))
+                                            class=("dark:bg-white/30", move || highlighted() == Some(index))
+                                            // mousedown instead of click, because click would only fire after the input lost focus and the suggestions were hidden
+                                            on:mousedown=move |ev| {
+                                                ev.prevent_default();
+                                                add(tag.clone());
+                                            }
+                                        >
+                                            {suggestion}
+                                        </li>
+                                    }
+                                })
+                                .collect_view()
+                        }
+                    }
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn CloseIcon() -> impl IntoView {
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            fill="none"
+            viewBox="0 0 24 24"
+            stroke-width="1.5"
+            stroke="currentColor"
+            class="h-3 w-3">
+            <path stroke-linecap="round" stroke-linejoin="round" d="M6 18L18 6M6 6l12 12" />
+        </svg>
+    }
+}
@@ -0,0 +1,122 @@
+use leptos::{html::Div, *};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+/// A Collapse component. It shows or hides its children with a sliding animation.
+///
+/// See [Tailwind Elements: Collapse](https://tailwind-elements.com/docs/standard/components/collapse/)
+#[component]
+pub fn Collapse(
+    /// The children are shown whenever this signal is `true` and hidden whenever it is `false`.
+    #[prop(into)]
+    open: MaybeSignal<bool>,
+    /// Callback that is called with `true` when the collapse finished showing and with `false` when it finished hiding.
+    #[prop(into, optional)]
+    on_toggle: Option<Callback<bool>>,
+    /// Content that is shown or hidden
+    children: Children,
+) -> impl IntoView {
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let options = JsCollapseOptions { toggle: false };
+            let jscollapse = Rc::new(JsCollapse::new(
+                &element,
+                serde_wasm_bindgen::to_value(&options).unwrap(),
+            ));
+
+            let on_shown = on_toggle.clone();
+            let on_shown: Closure<dyn FnMut()> = Closure::new(move || {
+                if let Some(on_shown) = &on_shown {
+                    on_shown(true);
+                }
+            });
+            let on_hidden = on_toggle.clone();
+            let on_hidden: Closure<dyn FnMut()> = Closure::new(move || {
+                if let Some(on_hidden) = &on_hidden {
+                    on_hidden(false);
+                }
+            });
+            te_collapse_add_event_listener(&element, "shown.te.collapse", &on_shown);
+            te_collapse_add_event_listener(&element, "hidden.te.collapse", &on_hidden);
+
+            {
+                let jscollapse = Rc::clone(&jscollapse);
+                create_effect(move |_| {
+                    if open() {
+                        jscollapse.show();
+                    } else {
+                        jscollapse.hide();
+                    }
+                });
+            }
+
+            on_cleanup(move || {
+                jscollapse.dispose();
+                std::mem::drop(on_shown);
+                std::mem::drop(on_hidden);
+            });
+        }
+    });
+
+    // Only the initial state is rendered here. Afterwards, Tailwind Elements takes care of the classes and attributes.
+    let initially_open = open.get_untracked();
+
+    view! {
+        <div
+            ref=element_ref
+            class=if initially_open { "!visible" } else { "!visible hidden" }
+            data-te-collapse-item
+            data-te-collapse-show=initially_open
+        >
+            {children()}
+        </div>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Collapse)]
+    pub(crate) type JsCollapse;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Collapse, final)]
+    pub(crate) fn new(e: &HtmlElement, options: JsValue) -> JsCollapse;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Collapse, final)]
+    pub(crate) fn show(this: &JsCollapse);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Collapse, final)]
+    pub(crate) fn hide(this: &JsCollapse);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Collapse, final)]
+    pub(crate) fn dispose(this: &JsCollapse);
+}
+
+#[wasm_bindgen(
+    inline_js = "export function te_collapse_add_event_listener(collapse_html_elem, event_name, callback) { collapse_html_elem.addEventListener(event_name, (event) => {
+        // Nested collapses bubble their events up, only handle our own.
+        if (event.target === collapse_html_elem) {
+            callback();
+        }
+    }); }"
+)]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_collapse_add_event_listener(
+        collapse: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct JsCollapseOptions {
+    pub(crate) toggle: bool,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/components/collapse/#docsTabsAPI
+}
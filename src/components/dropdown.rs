@@ -1,72 +1,574 @@
-use leptos::{html::Div, *};
-use wasm_bindgen::prelude::wasm_bindgen;
-use web_sys::HtmlDivElement;
+use leptos::{
+    ev::KeyboardEvent,
+    html::{Button, Ul},
+    *,
+};
+use serde::Serialize;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+    JsCast,
+};
+use web_sys::HtmlElement;
 
-/// A Dropdown component
+use super::{unique_id::unique_id, ButtonStyle};
+
+/// An item in the menu of a [Dropdown].
+#[derive(Clone)]
+pub enum DropdownItem {
+    /// An item that calls `on_select` when it is clicked
+    Action {
+        /// The text of the item
+        label: String,
+        /// Callback that is called when the item is clicked
+        on_select: Callback<()>,
+        /// Whether the item is disabled, i.e. can't be clicked
+        disabled: bool,
+    },
+    /// An item that links to another page
+    Link {
+        /// The text of the item
+        label: String,
+        /// The target of the link
+        href: String,
+        /// Whether the item is disabled, i.e. can't be clicked
+        disabled: bool,
+    },
+    /// A header to give a title to the items following it
+    Header {
+        /// The text of the header
+        label: String,
+    },
+    /// A horizontal line to separate groups of items
+    Divider,
+    /// An item that opens a nested menu when it is hovered, clicked or selected with the keyboard
+    Submenu {
+        /// The text of the item
+        label: String,
+        /// The items of the nested menu
+        items: Vec<DropdownItem>,
+    },
+}
+
+impl DropdownItem {
+    /// Create a [DropdownItem::Action] item that isn't disabled.
+    pub fn action(label: impl Into<String>, on_select: impl Into<Callback<()>>) -> Self {
+        Self::Action {
+            label: label.into(),
+            on_select: on_select.into(),
+            disabled: false,
+        }
+    }
+
+    /// Create a [DropdownItem::Link] item that isn't disabled.
+    pub fn link(label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self::Link {
+            label: label.into(),
+            href: href.into(),
+            disabled: false,
+        }
+    }
+
+    /// Create a [DropdownItem::Header] item.
+    pub fn header(label: impl Into<String>) -> Self {
+        Self::Header {
+            label: label.into(),
+        }
+    }
+
+    /// Create a [DropdownItem::Submenu] item.
+    pub fn submenu(label: impl Into<String>, items: Vec<DropdownItem>) -> Self {
+        Self::Submenu {
+            label: label.into(),
+            items,
+        }
+    }
+}
+
+/// Where the menu of a [Dropdown] is shown relative to its trigger.
+///
+/// See [Tailwind Elements: Dropdown#Directions](https://tailwind-elements.com/docs/standard/components/dropdown/#dropup)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DropdownPlacement {
+    /// Show the menu below the trigger
+    #[default]
+    Down,
+    /// Show the menu above the trigger
+    Up,
+    /// Show the menu to the right of the trigger
+    End,
+    /// Show the menu to the left of the trigger
+    Start,
+}
+
+impl DropdownPlacement {
+    const fn position_attr(self) -> Option<&'static str> {
+        match self {
+            Self::Down => None,
+            Self::Up => Some("dropup"),
+            Self::End => Some("dropend"),
+            Self::Start => Some("dropstart"),
+        }
+    }
+}
+
+/// When the menu of a [Dropdown] closes itself.
+///
+/// See [Tailwind Elements: Dropdown#Auto close behavior](https://tailwind-elements.com/docs/standard/components/dropdown/#auto-close-behavior)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DropdownAutoClose {
+    /// Close the menu when clicking inside or outside of it
+    #[default]
+    Always,
+    /// Only close the menu when clicking inside of it
+    Inside,
+    /// Only close the menu when clicking outside of it
+    Outside,
+    /// Only close the menu when clicking the trigger again or pressing escape
+    Manual,
+}
+
+impl DropdownAutoClose {
+    fn to_js(self) -> JsValue {
+        match self {
+            Self::Always => JsValue::TRUE,
+            Self::Inside => JsValue::from_str("inside"),
+            Self::Outside => JsValue::from_str("outside"),
+            Self::Manual => JsValue::FALSE,
+        }
+    }
+}
+
+/// A custom trigger for a [Dropdown], replacing the default button. Pass it with `<DropdownTrigger slot>`.
+#[slot]
+pub struct DropdownTrigger {
+    /// The content of the trigger
+    children: Children,
+}
+
+#[derive(Clone, Copy)]
+struct DropdownContext {
+    shown: ReadSignal<bool>,
+    typeahead: StoredValue<Typeahead>,
+}
+
+/// A Dropdown component, i.e. a button that opens a menu of items.
+///
+/// The menu can be used with the keyboard: Arrow keys, home and end move between the items,
+/// typing the start of an item's text jumps to it, arrow right opens a submenu and arrow left or escape closes it again.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Dropdown
+///         label="Actions"
+///         items=vec![
+///             DropdownItem::header("Item"),
+///             DropdownItem::action("Edit", move |()| edit()),
+///             DropdownItem::action("Delete", move |()| delete()),
+///             DropdownItem::submenu("Share", vec![
+///                 DropdownItem::action("Email", move |()| share_via_email()),
+///                 DropdownItem::action("Copy link", move |()| copy_link()),
+///             ]),
+///             DropdownItem::Divider,
+///             DropdownItem::link("Help", "/help"),
+///         ]
+///     />
+/// }
+/// ```
 ///
 /// See [Tailwind Elements: Dropdown](https://tailwind-elements.com/docs/standard/components/dropdown)
 #[component]
-pub fn Dropdown() -> impl IntoView {
+pub fn Dropdown(
+    /// The items of the menu. See [DropdownItem].
+    #[prop(into)]
+    items: MaybeSignal<Vec<DropdownItem>>,
+    /// The text of the button that opens the menu. Ignored if a [DropdownTrigger] is given.
+    #[prop(into, default = "".into())]
+    label: MaybeSignal<String>,
+    /// The style of the button that opens the menu. Ignored if a [DropdownTrigger] is given.
+    #[prop(into, default = ButtonStyle::Primary.into())]
+    button_style: MaybeSignal<ButtonStyle>,
+    /// A custom trigger replacing the default button. See [DropdownTrigger].
+    #[prop(optional)]
+    dropdown_trigger: Option<DropdownTrigger>,
+    /// Where the menu is shown relative to the trigger. See [DropdownPlacement].
+    #[prop(default = DropdownPlacement::Down)]
+    placement: DropdownPlacement,
+    /// The distance of the menu from the trigger in pixels, as `(skidding, distance)`.
+    #[prop(default = (0, 2))]
+    offset: (i32, i32),
+    /// When the menu closes itself. See [DropdownAutoClose].
+    #[prop(default = DropdownAutoClose::Always)]
+    auto_close: DropdownAutoClose,
+    /// Callback that is called when the menu starts showing.
+    #[prop(into, optional)]
+    on_show: Option<Callback<()>>,
+    /// Callback that is called when the menu starts hiding.
+    #[prop(into, optional)]
+    on_hide: Option<Callback<()>>,
+) -> impl IntoView {
+    let toggle_id = unique_id("te-dropdown");
+    let (shown, set_shown) = create_signal(false);
+    let typeahead = store_value(Typeahead::default());
+    provide_context(DropdownContext { shown, typeahead });
+
     // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
-    let element_ref: NodeRef<Div> = create_node_ref();
+    let toggle_ref: NodeRef<Button> = create_node_ref();
     create_effect(move |_| {
-        if let Some(element) = element_ref() {
-            let jsdropdown = JsDropdown::new(&element);
-            on_cleanup(move || jsdropdown.dispose());
+        if let Some(element) = toggle_ref() {
+            let options = JsDropdownOptions {
+                offset: [offset.0, offset.1],
+                auto_close: auto_close.to_js(),
+            };
+            let jsdropdown =
+                JsDropdown::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
+
+            let on_show = on_show.clone();
+            let on_show: Closure<dyn FnMut()> = Closure::new(move || {
+                set_shown(true);
+                if let Some(on_show) = &on_show {
+                    on_show(());
+                }
+            });
+            let on_hide = on_hide.clone();
+            let on_hide: Closure<dyn FnMut()> = Closure::new(move || {
+                set_shown(false);
+                if let Some(on_hide) = &on_hide {
+                    on_hide(());
+                }
+            });
+            te_dropdown_add_event_listener(&element, "show.te.dropdown", &on_show);
+            te_dropdown_add_event_listener(&element, "hide.te.dropdown", &on_hide);
+
+            on_cleanup(move || {
+                jsdropdown.dispose();
+                std::mem::drop(on_show);
+                std::mem::drop(on_hide);
+            });
         }
     });
 
-    view! {
-        <div ref=element_ref class="relative" data-te-dropdown-ref>
+    let trigger = match dropdown_trigger {
+        Some(trigger) => view! {
             <button
-                class="flex items-center whitespace-nowrap rounded bg-primary px-6 pb-2 pt-2.5 text-xs font-medium uppercase leading-normal text-white shadow-[0_4px_9px_-4px_#3b71ca] transition duration-150 ease-in-out hover:bg-primary-600 hover:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] focus:bg-primary-600 focus:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] focus:outline-none focus:ring-0 active:bg-primary-700 active:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] motion-reduce:transition-none dark:shadow-[0_4px_9px_-4px_rgba(59,113,202,0.5)] dark:hover:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)] dark:focus:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)] dark:active:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)]"
+                ref=toggle_ref
+                class="flex items-center whitespace-nowrap"
                 type="button"
-                id="dropdownMenuButton1"
+                id=toggle_id.clone()
                 data-te-dropdown-toggle-ref
-                aria-expanded="false"
+                aria-haspopup="menu"
+                aria-expanded=move || shown().to_string()
+            >
+                {(trigger.children)()}
+            </button>
+        },
+        None => view! {
+            <button
+                ref=toggle_ref
+                class=move || format!("{} rounded whitespace-nowrap", button_style().classes())
+                type="button"
+                id=toggle_id.clone()
+                data-te-dropdown-toggle-ref
+                aria-haspopup="menu"
+                aria-expanded=move || shown().to_string()
                 data-te-ripple-init
                 data-te-ripple-color="light"
             >
-                "Dropdown button"
-                <span class="ml-2 w-2">
-                    <DropdownCaret />
+                <span class="flex items-center">
+                    {label}
+                    <span class="ml-2 w-2">
+                        <DropdownCaret />
+                    </span>
                 </span>
             </button>
+        },
+    };
+
+    view! {
+        <div
+            class="relative"
+            data-te-dropdown-ref
+            data-te-dropdown-position=placement.position_attr()
+        >
+            {trigger}
+            // No `overflow-hidden` here, it would cut off submenus
             <ul
-                class="absolute z-[1000] float-left m-0 hidden min-w-max list-none overflow-hidden rounded-lg border-none bg-white bg-clip-padding text-left text-base shadow-lg dark:bg-neutral-700 [&[data-te-dropdown-show]]:block"
-                aria-labelledby="dropdownMenuButton1"
+                class="absolute z-[1000] float-left m-0 hidden min-w-max list-none rounded-lg border-none bg-white bg-clip-padding py-1 text-left text-base shadow-lg dark:bg-neutral-700 [&[data-te-dropdown-show]]:block"
+                role="menu"
+                aria-labelledby=toggle_id
                 data-te-dropdown-menu-ref
+                // Undelegated, so we can stop the event before Tailwind Elements' keyboard handling on the document sees it.
+                // Escape isn't handled here, so Tailwind Elements still closes the menu.
+                on:keydown:undelegated=move |ev: KeyboardEvent| {
+                    if navigate_menu(&ev, typeahead) {
+                        ev.prevent_default();
+                        ev.stop_propagation();
+                    }
+                }
             >
-                <li>
-                    <a
-                        class="block w-full whitespace-nowrap bg-transparent px-4 py-2 text-sm font-normal text-neutral-700 hover:bg-neutral-100 active:text-neutral-800 active:no-underline disabled:pointer-events-none disabled:bg-transparent disabled:text-neutral-400 dark:text-neutral-200 dark:hover:bg-neutral-600"
-                        href="#"
-                        data-te-dropdown-item-ref
-                    >
-                        "Action"
-                    </a>
-                </li>
-                <li>
+                {move || items().into_iter().map(dropdown_item).collect_view()}
+            </ul>
+        </div>
+    }
+}
+
+const ITEM_CLASS: &str = "block w-full whitespace-nowrap bg-transparent px-4 py-2 text-left text-sm font-normal text-neutral-700 hover:bg-neutral-100 active:text-neutral-800 active:no-underline disabled:pointer-events-none disabled:bg-transparent disabled:text-neutral-400 dark:text-neutral-200 dark:hover:bg-neutral-600";
+
+fn dropdown_item(item: DropdownItem) -> View {
+    match item {
+        DropdownItem::Action {
+            label,
+            on_select,
+            disabled,
+        } => view! {
+            <li role="none">
+                <button
+                    type="button"
+                    class=ITEM_CLASS
+                    role="menuitem"
+                    disabled=disabled
+                    data-te-dropdown-item-ref
+                    on:click=move |_| on_select(())
+                >
+                    {label}
+                </button>
+            </li>
+        }
+        .into_view(),
+        DropdownItem::Link {
+            label,
+            href,
+            disabled: false,
+        } => view! {
+            <li role="none">
+                <a class=ITEM_CLASS href=href role="menuitem" data-te-dropdown-item-ref>
+                    {label}
+                </a>
+            </li>
+        }
+        .into_view(),
+        // Links can't be disabled, so we leave out the `href` and style it like a disabled button
+        DropdownItem::Link {
+            label,
+            disabled: true,
+            ..
+        } => view! {
+            <li role="none">
                 <a
-                    class="block w-full whitespace-nowrap bg-transparent px-4 py-2 text-sm font-normal text-neutral-700 hover:bg-neutral-100 active:text-neutral-800 active:no-underline disabled:pointer-events-none disabled:bg-transparent disabled:text-neutral-400 dark:text-neutral-200 dark:hover:bg-neutral-600"
-                    href="#"
+                    class=format!("{ITEM_CLASS} pointer-events-none !text-neutral-400")
+                    role="menuitem"
+                    aria-disabled="true"
                     data-te-dropdown-item-ref
                 >
-                    "Another Action"
+                    {label}
                 </a>
             </li>
-            <li>
-            <a
-                class="block w-full whitespace-nowrap bg-transparent px-4 py-2 text-sm font-normal text-neutral-700 hover:bg-neutral-100 active:text-neutral-800 active:no-underline disabled:pointer-events-none disabled:bg-transparent disabled:text-neutral-400 dark:text-neutral-200 dark:hover:bg-neutral-600"
-                href="#"
+        }
+        .into_view(),
+        DropdownItem::Header { label } => view! {
+            <li role="presentation">
+                <h6 class="block bg-transparent px-4 py-2 text-sm font-normal text-neutral-500 dark:text-neutral-200">
+                    {label}
+                </h6>
+            </li>
+        }
+        .into_view(),
+        DropdownItem::Divider => view! {
+            <li role="none">
+                <hr
+                    class="my-2 h-0 border border-t-0 border-solid border-neutral-700 opacity-25 dark:border-neutral-200"
+                    role="separator"
+                />
+            </li>
+        }
+        .into_view(),
+        DropdownItem::Submenu { label, items } => view! {
+            <DropdownSubmenu label=label items=items />
+        }
+        .into_view(),
+    }
+}
+
+#[component]
+fn DropdownSubmenu(label: String, items: Vec<DropdownItem>) -> impl IntoView {
+    let dropdown = use_context::<DropdownContext>().expect("Expected Dropdown in context");
+    let (open, set_open) = create_signal(false);
+    // Submenus are closed together with the whole menu, so they don't pop up when it is opened again
+    create_effect(move |_| {
+        if !dropdown.shown.get() {
+            set_open(false);
+        }
+    });
+
+    let trigger_ref: NodeRef<Button> = create_node_ref();
+    let submenu_ref: NodeRef<Ul> = create_node_ref();
+    let open_and_focus = move || {
+        set_open(true);
+        if let Some(submenu) = submenu_ref.get_untracked() {
+            if let Some(first) = menu_items(&submenu).first() {
+                let _ = first.focus();
+            }
+        }
+    };
+    let close_and_focus = move || {
+        set_open(false);
+        if let Some(trigger) = trigger_ref.get_untracked() {
+            let _ = trigger.focus();
+        }
+    };
+
+    view! {
+        <li
+            class="relative"
+            role="none"
+            on:mouseenter=move |_| set_open(true)
+            on:mouseleave=move |_| set_open(false)
+        >
+            <button
+                ref=trigger_ref
+                type="button"
+                class=format!("{ITEM_CLASS} flex items-center justify-between")
+                role="menuitem"
+                aria-haspopup="menu"
+                aria-expanded=move || open().to_string()
                 data-te-dropdown-item-ref
+                // Undelegated, so we can stop the click before Tailwind Elements sees it and closes the whole menu
+                on:click:undelegated=move |ev| {
+                    ev.stop_propagation();
+                    set_open(true);
+                }
+                on:keydown:undelegated=move |ev: KeyboardEvent| {
+                    if matches!(ev.key().as_str(), "ArrowRight" | "Enter" | " ") {
+                        ev.prevent_default();
+                        ev.stop_propagation();
+                        open_and_focus();
+                    }
+                }
             >
-                "Something else here"
-            </a>
-        </li>
+                {label}
+                <span class="ml-2 w-2 -rotate-90">
+                    <DropdownCaret />
+                </span>
+            </button>
+            <ul
+                ref=submenu_ref
+                class="absolute left-full top-0 z-[1000] m-0 min-w-max list-none rounded-lg border-none bg-white bg-clip-padding py-1 text-left text-base shadow-lg dark:bg-neutral-700"
+                class=("hidden", move || !open())
+                role="menu"
+                on:keydown:undelegated=move |ev: KeyboardEvent| {
+                    let handled = match ev.key().as_str() {
+                        "ArrowLeft" | "Escape" => {
+                            close_and_focus();
+                            true
+                        }
+                        _ => navigate_menu(&ev, dropdown.typeahead),
+                    };
+                    if handled {
+                        ev.prevent_default();
+                        ev.stop_propagation();
+                    }
+                }
+            >
+                {items.into_iter().map(dropdown_item).collect_view()}
             </ul>
-        </div>
+        </li>
+    }
+}
+
+#[derive(Default)]
+struct Typeahead {
+    query: String,
+    last_key_time: f64,
+}
+
+// How long after the last key press typing starts a new search instead of extending the current one
+const TYPEAHEAD_TIMEOUT_MS: f64 = 500.0;
+
+// The items of a menu that can be focused, not including the items of its submenus
+fn menu_items(menu: &web_sys::Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = menu.query_selector_all(
+        ":scope > li > [data-te-dropdown-item-ref]:not(:disabled):not([aria-disabled='true'])",
+    ) else {
+        return vec![];
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+// Moves the focus between the items of the menu the keydown event was registered on.
+// Returns whether the key was handled.
+fn navigate_menu(ev: &KeyboardEvent, typeahead: StoredValue<Typeahead>) -> bool {
+    let Some(menu) = ev
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+    else {
+        return false;
+    };
+    let items = menu_items(&menu);
+    if items.is_empty() {
+        return false;
     }
+    let num_items = items.len();
+    let active = document().active_element();
+    let current = active.and_then(|active| {
+        items
+            .iter()
+            .position(|item| AsRef::<web_sys::Element>::as_ref(item) == &active)
+    });
+
+    let key = ev.key();
+    let target = match key.as_str() {
+        "ArrowDown" => current.map_or(0, |index| (index + 1) % num_items),
+        "ArrowUp" => current.map_or(num_items - 1, |index| (index + num_items - 1) % num_items),
+        "Home" => 0,
+        "End" => num_items - 1,
+        _ if key.chars().count() == 1
+            && key != " "
+            && !ev.ctrl_key()
+            && !ev.alt_key()
+            && !ev.meta_key() =>
+        {
+            let now = ev.time_stamp();
+            let query = typeahead.with_value(|typeahead| {
+                if now - typeahead.last_key_time > TYPEAHEAD_TIMEOUT_MS {
+                    key.to_lowercase()
+                } else {
+                    format!("{}{}", typeahead.query, key.to_lowercase())
+                }
+            });
+            typeahead.set_value(Typeahead {
+                query: query.clone(),
+                last_key_time: now,
+            });
+            // Typing the same letter repeatedly cycles through the items starting with it
+            let start = match current {
+                Some(index) if query.chars().count() == 1 => index + 1,
+                Some(index) => index,
+                None => 0,
+            };
+            let found = (0..num_items)
+                .map(|offset| (start + offset) % num_items)
+                .find(|&index| {
+                    items[index]
+                        .text_content()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_lowercase()
+                        .starts_with(&query)
+                });
+            match found {
+                Some(index) => index,
+                None => return true,
+            }
+        }
+        _ => return false,
+    };
+    let _ = items[target].focus();
+    true
 }
 
 #[component]
@@ -91,10 +593,33 @@ extern "C" {
     #[wasm_bindgen(js_namespace = te, js_name = Dropdown)]
     type JsDropdown;
 
-    // TODO Dropdown constructor can take some options, see https://tailwind-elements.com/docs/standard/components/dropdown/#docsTabsAPI
     #[wasm_bindgen(constructor, js_namespace = te, js_class = Dropdown, final)]
-    fn new(e: &HtmlDivElement) -> JsDropdown;
+    fn new(e: &HtmlElement, options: JsValue) -> JsDropdown;
 
     #[wasm_bindgen(method, js_namespace = te, js_class = Dropdown, final)]
     fn dispose(this: &JsDropdown);
 }
+
+#[wasm_bindgen(
+    inline_js = "export function te_dropdown_add_event_listener(dropdown_html_elem, event_name, callback) { dropdown_html_elem.addEventListener(event_name, (event) => {
+        callback();
+    }); }"
+)]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_dropdown_add_event_listener(
+        dropdown: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    );
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsDropdownOptions {
+    offset: [i32; 2],
+
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    auto_close: JsValue,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/components/dropdown/#docsTabsAPI
+}
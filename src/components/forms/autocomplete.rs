@@ -0,0 +1,214 @@
+use leptos::{ev::KeyboardEvent, leptos_dom::helpers::TimeoutHandle, *};
+use std::future::Future;
+use std::rc::Rc;
+use std::time::Duration;
+
+use super::{Input, SelectOption};
+use crate::components::unique_id::unique_id;
+use crate::components::{Spinner, SpinnerSize};
+
+/// An Autocomplete component, i.e. a text input that suggests options matching the entered text.
+///
+/// The suggestions are loaded with the async `fetcher` function, e.g. from a server. It is called with
+/// the entered text once the user stopped typing for the `debounce` duration, and a [Spinner] is shown while it runs.
+/// The suggestions can be chosen with the mouse or with the arrow keys and enter.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Autocomplete
+///         label="City"
+///         fetcher=|query: String| async move { search_cities(query).await }
+///         on_select=move |city: City| set_city(Some(city))
+///     />
+/// }
+/// ```
+#[component]
+pub fn Autocomplete<O, FetchFn, FetchFuture, OnSelectFn>(
+    /// The id of the input field. Useful to associate a label with it.
+    #[prop(into, default = "".into())]
+    id: String,
+    /// The label of the input field.
+    #[prop(into, default = "".into())]
+    label: MaybeSignal<String>,
+    /// Async function that is called with the entered text and returns the matching options.
+    fetcher: FetchFn,
+    /// Callback that is called when the user chooses one of the suggestions.
+    on_select: OnSelectFn,
+    /// How long to wait after the last keystroke before calling `fetcher`.
+    #[prop(into, default = Duration::from_millis(300))]
+    debounce: Duration,
+    /// How many characters have to be entered before suggestions are shown.
+    #[prop(default = 1)]
+    min_length: usize,
+    /// The text shown when `fetcher` didn't return any options.
+    #[prop(into, default = "No results".into())]
+    no_results_text: String,
+) -> impl IntoView
+where
+    O: SelectOption + Clone + 'static,
+    FetchFn: Fn(String) -> FetchFuture + 'static,
+    FetchFuture: Future<Output = Vec<O>> + 'static,
+    OnSelectFn: Fn(O) + 'static,
+{
+    let list_id = unique_id("te-autocomplete-list");
+    let (query, set_query) = create_signal(String::new());
+    let (debounced_query, set_debounced_query) = create_signal(String::new());
+    let (open, set_open) = create_signal(false);
+    // Index of the suggestion that is highlighted with the arrow keys
+    let (active, set_active) = create_signal(None::<usize>);
+
+    let timeout = store_value(None::<TimeoutHandle>);
+    let cancel_timeout = move || {
+        if let Some(timeout) = timeout.get_value() {
+            timeout.clear();
+        }
+    };
+    on_cleanup(cancel_timeout);
+    let on_change = move |text: String| {
+        set_query(text.clone());
+        set_open(true);
+        cancel_timeout();
+        timeout
+            .set_value(set_timeout_with_handle(move || set_debounced_query(text), debounce).ok());
+    };
+
+    let fetcher = Rc::new(fetcher);
+    let results = create_local_resource(debounced_query, move |query| {
+        let fetcher = Rc::clone(&fetcher);
+        async move {
+            if query.chars().count() < min_length {
+                vec![]
+            } else {
+                fetcher(query).await
+            }
+        }
+    });
+    let suggestions = Signal::derive(move || results.get().unwrap_or_default());
+    create_effect(move |_| {
+        results.track();
+        set_active(None);
+    });
+    let show_list = move || open() && query.with(|query| query.chars().count() >= min_length);
+
+    let on_select = store_value(on_select);
+    let list_ref: NodeRef<html::Ul> = create_node_ref();
+    let select = move |index: usize| {
+        let Some(option) = suggestions
+            .with_untracked(|suggestions| suggestions.get(index).cloned())
+            .filter(|option| !option.disabled())
+        else {
+            return;
+        };
+        // The input shows the text of the chosen option, because we only know how to render options as a view
+        let text = list_ref
+            .get_untracked()
+            .and_then(|list| list.children().item(index as u32))
+            .and_then(|item| item.text_content())
+            .unwrap_or_default();
+        set_query(text.trim().to_string());
+        set_open(false);
+        on_select.with_value(|on_select| on_select(option));
+    };
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let num_suggestions = suggestions.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" | "ArrowUp" => {
+                ev.prevent_default();
+                set_open(true);
+                if num_suggestions > 0 {
+                    let next = match (active.get_untracked(), ev.key() == "ArrowDown") {
+                        (None, true) => 0,
+                        (None, false) => num_suggestions - 1,
+                        (Some(index), true) => (index + 1) % num_suggestions,
+                        (Some(index), false) => (index + num_suggestions - 1) % num_suggestions,
+                    };
+                    set_active(Some(next));
+                }
+            }
+            "Enter" => {
+                if let Some(index) = active.get_untracked().filter(|_| open.get_untracked()) {
+                    ev.prevent_default();
+                    select(index);
+                }
+            }
+            "Escape" => set_open(false),
+            _ => {}
+        }
+    };
+
+    let option_id = {
+        let list_id = list_id.clone();
+        move |index: usize| format!("{list_id}-{index}")
+    };
+    let active_descendant = {
+        let option_id = option_id.clone();
+        move || active().filter(|_| show_list()).map(&option_id)
+    };
+    let expanded = move || show_list().to_string();
+
+    view! {
+        <div class="relative">
+            <Input
+                id=id
+                label=label
+                value=query
+                on_change=on_change
+                on_keydown=Callback::new(on_keydown)
+                on_blur=Callback::new(move |()| set_open(false))
+                attr:role="combobox"
+                attr:autocomplete="off"
+                attr:aria-autocomplete="list"
+                attr:aria-expanded=expanded
+                attr:aria-controls=list_id.clone()
+                attr:aria-activedescendant=active_descendant
+            />
+            <div
+                class="absolute left-0 right-0 top-full z-[1070] -mt-3 max-h-60 overflow-y-auto rounded bg-white shadow-[0_2px_5px_0_rgba(0,0,0,0.16),_0_2px_10px_0_rgba(0,0,0,0.12)] dark:bg-zinc-700"
+                class:hidden=move || !show_list()
+            >
+                <Show when=move || results.loading()() fallback=|| ()>
+                    <div class="flex items-center justify-center py-2 text-primary">
+                        <Spinner size=SpinnerSize::Small />
+                    </div>
+                </Show>
+                <ul
+                    ref=list_ref
+                    id=list_id
+                    class="m-0 list-none p-0"
+                    class:hidden=move || results.loading()()
+                    role="listbox"
+                >
+                    {move || suggestions.with(|suggestions| {
+                        suggestions.iter().enumerate().map(|(index, option)| {
+                            let is_active = move || active() == Some(index);
+                            view! {
+                                <li
+                                    id=option_id(index)
+                                    class="flex cursor-pointer items-center px-4 py-2 text-gray-700 hover:bg-black/5 data-[te-autocomplete-option-active]:bg-black/5 dark:text-gray-200 dark:hover:bg-white/30 dark:data-[te-autocomplete-option-active]:bg-white/30"
+                                    role="option"
+                                    aria-selected=move || is_active().to_string()
+                                    aria-disabled=option.disabled().then_some("true")
+                                    data-te-autocomplete-option-active=move || is_active().then_some("")
+                                    // Prevent the input from losing the focus, which would close the list before the click
+                                    on:mousedown=|ev| ev.prevent_default()
+                                    on:click=move |_| select(index)
+                                >
+                                    {option.view()}
+                                </li>
+                            }
+                        }).collect_view()
+                    })}
+                </ul>
+                <Show
+                    when=move || !results.loading()() && results.with(|results| results.as_ref().is_some_and(Vec::is_empty))
+                    fallback=|| ()
+                >
+                    <div class="px-4 py-2 text-gray-500 dark:text-gray-300">{no_results_text.clone()}</div>
+                </Show>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,391 @@
+use leptos::{
+    html::{Div, Input},
+    *,
+};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+use super::datepicker::{
+    format_date, is_date_in_range, js_date_string, parse_date, DatepickerDate, JsDatepickerOptions,
+};
+use super::input::JsInput;
+use super::timepicker::{
+    format_time, is_time_in_range, parse_time, JsTimepickerOptions, TimepickerFormat,
+    TimepickerTime,
+};
+
+/// A date and time type that can be used with a [DateTimepicker].
+///
+/// This is implemented for `chrono::NaiveDateTime` if the `chrono` feature is enabled
+/// and for `time::PrimitiveDateTime` if the `time` feature is enabled.
+pub trait DateTimepickerDateTime: Copy + 'static {
+    /// The date part, see [DatepickerDate]
+    type Date: DatepickerDate;
+
+    /// The time part, see [TimepickerTime]
+    type Time: TimepickerTime;
+
+    /// Combine a date and a time.
+    fn from_date_time(date: Self::Date, time: Self::Time) -> Self;
+
+    /// Split into date and time.
+    fn to_date_time(&self) -> (Self::Date, Self::Time);
+}
+
+#[cfg(feature = "chrono")]
+impl DateTimepickerDateTime for chrono::NaiveDateTime {
+    type Date = chrono::NaiveDate;
+    type Time = chrono::NaiveTime;
+
+    fn from_date_time(date: Self::Date, time: Self::Time) -> Self {
+        chrono::NaiveDateTime::new(date, time)
+    }
+
+    fn to_date_time(&self) -> (Self::Date, Self::Time) {
+        (self.date(), self.time())
+    }
+}
+
+#[cfg(feature = "time")]
+impl DateTimepickerDateTime for time::PrimitiveDateTime {
+    type Date = time::Date;
+    type Time = time::Time;
+
+    fn from_date_time(date: Self::Date, time: Self::Time) -> Self {
+        time::PrimitiveDateTime::new(date, time)
+    }
+
+    fn to_date_time(&self) -> (Self::Date, Self::Time) {
+        (self.date(), self.time())
+    }
+}
+
+/// A DateTimepicker component, i.e. a [Datepicker](super::Datepicker) and a [Timepicker](super::Timepicker) combined into one input field.
+///
+/// The date and time type is generic, see [DateTimepickerDateTime]. Enable the `chrono` or `time` feature to use it with those crates.
+///
+/// See [Tailwind Elements: Datetimepicker](https://tailwind-elements.com/docs/standard/forms/datetimepicker/)
+#[component]
+pub fn DateTimepicker<DT: DateTimepickerDateTime, OnChangeFn: Fn(Option<DT>) + 'static>(
+    /// This signal is read to show the currently selected date and time.
+    /// Whenever this signal changes, the datetimepicker is updated.
+    /// But note that the value can also be changed by the user and if the `on_change` function doesn't
+    /// update the `value` signal, the datetimepicker can get out of sync with the signal.
+    #[prop(into)]
+    value: MaybeSignal<Option<DT>>,
+    /// Callback that is called when the user selects a date and time, or with `None` when the user clears the input field.
+    on_change: OnChangeFn,
+    /// How the date part is shown in the input field. See the `format` property of [Datepicker](super::Datepicker).
+    #[prop(into, default = "dd/mm/yyyy".into())]
+    date_format: MaybeSignal<String>,
+    /// Whether to use a 12 hour or a 24 hour clock. See [TimepickerFormat].
+    #[prop(into, default = TimepickerFormat::Hours12.into())]
+    time_format: MaybeSignal<TimepickerFormat>,
+    /// Dates before this date can't be selected.
+    #[prop(into, default = None.into())]
+    min_date: MaybeSignal<Option<DT::Date>>,
+    /// Dates after this date can't be selected.
+    #[prop(into, default = None.into())]
+    max_date: MaybeSignal<Option<DT::Date>>,
+    /// Times before this time can't be selected, on any date.
+    #[prop(into, default = None.into())]
+    min_time: MaybeSignal<Option<DT::Time>>,
+    /// Times after this time can't be selected, on any date.
+    #[prop(into, default = None.into())]
+    max_time: MaybeSignal<Option<DT::Time>>,
+    /// If `true`, the minutes can only be selected in steps of 5 minutes.
+    #[prop(into, default = false.into())]
+    increment: MaybeSignal<bool>,
+    /// If `true`, the calendar is shown in a dropdown below the input field. If `false`, it is shown in a modal.
+    #[prop(into, default = false.into())]
+    inline: MaybeSignal<bool>,
+    /// Whether the datetimepicker is disabled.
+    #[prop(into, default = false.into())]
+    disabled: MaybeSignal<bool>,
+    /// The id of the input field. Useful to associate a label with it.
+    #[prop(into, default = "".into())]
+    id: String,
+    /// This label is shown as a placeholder while the field is empty and it is shown as a label at the top of the input field when it is non-empty or has focus.
+    #[prop(into, default = "".into())]
+    label: MaybeSignal<String>,
+) -> impl IntoView {
+    let date_format = Signal::derive(move || date_format.get());
+    let on_change = store_value(on_change);
+    let on_input = move |text: String| {
+        if text.trim().is_empty() {
+            on_change.with_value(|on_change| on_change(None));
+        } else if let Some(value) = date_format
+            .with_untracked(|date_format| parse_date_time::<DT>(date_format, &text))
+            // Tailwind Elements doesn't check typed values against the limits, so we do it here.
+            .filter(|value| {
+                let (date, time) = value.to_date_time();
+                is_date_in_range(&date, min_date.get_untracked(), max_date.get_untracked())
+                    && is_time_in_range(&time, min_time.get_untracked(), max_time.get_untracked())
+            })
+        {
+            on_change.with_value(|on_change| on_change(Some(value)));
+        }
+        // Otherwise, the user is still typing and the input isn't a valid date and time yet, or it can't be selected.
+    };
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Div> = create_node_ref();
+    let input_ref: NodeRef<Input> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let time_format = time_format();
+            let options = JsDateTimepickerOptions {
+                datepicker: JsDatepickerOptions {
+                    format: date_format(),
+                    min: min_date().map(|date| js_date_string(&date)),
+                    max: max_date().map(|date| js_date_string(&date)),
+                    filter: JsValue::NULL,
+                    // Tailwind Elements loses all timepicker options if the top level `inline` option is set,
+                    // so we only show the date part inline.
+                    inline: inline(),
+                },
+                timepicker: JsTimepickerOptions {
+                    format12: time_format == TimepickerFormat::Hours12,
+                    format24: time_format == TimepickerFormat::Hours24,
+                    min_time: min_time()
+                        .map(|time| format_time(time_format, &time))
+                        .unwrap_or_default(),
+                    max_time: max_time()
+                        .map(|time| format_time(time_format, &time))
+                        .unwrap_or_default(),
+                    increment: increment(),
+                    inline: false,
+                },
+            };
+            let jsinput = JsInput::new(&element);
+            let jsdatetimepicker =
+                JsDateTimepicker::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
+
+            // Tailwind Elements doesn't trigger an input event when the user picks a date and time.
+            let on_datetime_change: Closure<dyn FnMut()> = Closure::new(move || {
+                if let Some(input) = input_ref.get_untracked() {
+                    on_input(input.value());
+                }
+            });
+            let datetime_change_listener = te_date_timepicker_add_event_listener(
+                &element,
+                "datetimeChange.te.datetimepicker",
+                &on_datetime_change,
+            );
+
+            on_cleanup(move || {
+                te_date_timepicker_remove_event_listener(
+                    &element,
+                    "datetimeChange.te.datetimepicker",
+                    &datetime_change_listener,
+                );
+                jsdatetimepicker.dispose();
+                jsinput.dispose();
+                std::mem::drop(on_datetime_change);
+            });
+        }
+    });
+
+    let id = if id.is_empty() { None } else { Some(id) };
+    let text = move || {
+        value()
+            .map(|value| {
+                date_format.with(|date_format| format_date_time(date_format, time_format(), &value))
+            })
+            .unwrap_or_default()
+    };
+
+    view! {
+        <div ref=element_ref class="relative mb-3">
+            <input
+                ref=input_ref
+                type="text"
+                class="peer block min-h-[auto] w-full rounded border-0 bg-transparent px-3 py-[0.32rem] leading-[1.6] outline-none transition-all duration-200 ease-linear focus:placeholder:opacity-100 peer-focus:text-primary data-[te-input-state-active]:placeholder:opacity-100 motion-reduce:transition-none dark:text-neutral-200 dark:placeholder:text-neutral-200 dark:peer-focus:text-primary [&:not([data-te-input-placeholder-active])]:placeholder:opacity-0"
+                placeholder=label.clone()
+                aria-label=label.clone()
+                id=id.clone()
+                disabled=disabled
+                prop:value=text
+                on:input=move |ev| on_input(event_target_value(&ev))
+            />
+            <label
+                for=id
+                class="pointer-events-none absolute left-3 top-0 mb-0 max-w-[90%] origin-[0_0] truncate pt-[0.37rem] leading-[1.6] text-neutral-500 transition-all duration-200 ease-out peer-focus:-translate-y-[0.9rem] peer-focus:scale-[0.8] peer-focus:text-primary peer-data-[te-input-state-active]:-translate-y-[0.9rem] peer-data-[te-input-state-active]:scale-[0.8] motion-reduce:transition-none dark:text-neutral-200 dark:peer-focus:text-primary"
+            >
+                {label}
+            </label>
+        </div>
+    }
+}
+
+// Tailwind Elements separates date and time with ", " in the input field.
+fn format_date_time<DT: DateTimepickerDateTime>(
+    date_format: &str,
+    time_format: TimepickerFormat,
+    value: &DT,
+) -> String {
+    let (date, time) = value.to_date_time();
+    format!(
+        "{}, {}",
+        format_date(date_format, &date),
+        format_time(time_format, &time)
+    )
+}
+
+fn parse_date_time<DT: DateTimepickerDateTime>(date_format: &str, input: &str) -> Option<DT> {
+    // The date format may contain ", " as well, but the time never does
+    let (date, time) = input.rsplit_once(", ")?;
+    Some(DT::from_date_time(
+        parse_date(date_format, date)?,
+        parse_time(time)?,
+    ))
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Datetimepicker)]
+    type JsDateTimepicker;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Datetimepicker, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsDateTimepicker;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Datetimepicker, final)]
+    fn dispose(this: &JsDateTimepicker);
+}
+
+#[wasm_bindgen(inline_js = "
+    export function te_date_timepicker_add_event_listener(date_timepicker_html_elem, event_name, callback) {
+        const listener = (event) => {
+            callback();
+        };
+        date_timepicker_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_date_timepicker_remove_event_listener(date_timepicker_html_elem, event_name, listener) {
+        date_timepicker_html_elem.removeEventListener(event_name, listener);
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_date_timepicker_add_event_listener(
+        date_timepicker: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_date_timepicker_remove_event_listener(
+        date_timepicker: &web_sys::HtmlElement,
+        event_name: &str,
+        listener: &JsValue,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsDateTimepickerOptions {
+    datepicker: JsDatepickerOptions,
+
+    timepicker: JsTimepickerOptions,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/forms/datetimepicker/#docsTabsAPI
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    mod generic {
+        use super::super::*;
+        use std::fmt::Debug;
+
+        const DATE_FORMATS: &[&str] = &["dd/mm/yyyy", "yyyy-mm-dd", "ddd, dd mmm yyyy"];
+
+        fn date_time<DT: DateTimepickerDateTime>(
+            (year, month, day): (i32, u32, u32),
+            (hour, minute): (u32, u32),
+        ) -> Option<DT> {
+            Some(DT::from_date_time(
+                DT::Date::from_ymd(year, month, day)?,
+                DT::Time::from_hm(hour, minute)?,
+            ))
+        }
+
+        pub fn round_trip<DT: DateTimepickerDateTime + Debug + PartialEq>() {
+            for (date, time) in [((2023, 10, 5), (21, 30)), ((2024, 2, 29), (0, 0))] {
+                let value = date_time::<DT>(date, time).unwrap();
+                for date_format in DATE_FORMATS {
+                    for time_format in [TimepickerFormat::Hours12, TimepickerFormat::Hours24] {
+                        let text = format_date_time(date_format, time_format, &value);
+                        assert_eq!(
+                            Some(value),
+                            parse_date_time::<DT>(date_format, &text),
+                            "{date_format:?}: {text:?}"
+                        );
+                    }
+                }
+            }
+        }
+
+        pub fn parse<DT: DateTimepickerDateTime + Debug + PartialEq>() {
+            let value = date_time::<DT>((2023, 10, 5), (21, 30));
+            assert_eq!(
+                value,
+                parse_date_time::<DT>("dd/mm/yyyy", "05/10/2023, 09:30 PM")
+            );
+            assert_eq!(
+                value,
+                parse_date_time::<DT>("dd/mm/yyyy", "05/10/2023, 21:30")
+            );
+            // The date format contains ", " as well
+            assert_eq!(
+                value,
+                parse_date_time::<DT>("ddd, dd mmm yyyy", "Thu, 05 Oct 2023, 09:30 PM")
+            );
+            assert_eq!(None, parse_date_time::<DT>("dd/mm/yyyy", "05/10/2023"));
+            assert_eq!(
+                None,
+                parse_date_time::<DT>("dd/mm/yyyy", "05/10/2023 09:30 PM")
+            );
+            assert_eq!(
+                None,
+                parse_date_time::<DT>("dd/mm/yyyy", "05/10/2023, 13:00 PM")
+            );
+            assert_eq!(
+                None,
+                parse_date_time::<DT>("dd/mm/yyyy", "31/02/2023, 09:30 PM")
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono {
+        use super::generic;
+
+        #[test]
+        fn round_trip() {
+            generic::round_trip::<::chrono::NaiveDateTime>();
+        }
+
+        #[test]
+        fn parse() {
+            generic::parse::<::chrono::NaiveDateTime>();
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time {
+        use super::generic;
+
+        #[test]
+        fn round_trip() {
+            generic::round_trip::<::time::PrimitiveDateTime>();
+        }
+
+        #[test]
+        fn parse() {
+            generic::parse::<::time::PrimitiveDateTime>();
+        }
+    }
+}
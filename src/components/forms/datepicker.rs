@@ -0,0 +1,620 @@
+use leptos::{
+    html::{Div, Input},
+    *,
+};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+use super::input::JsInput;
+
+/// A date type that can be used with a [Datepicker].
+///
+/// This is implemented for `chrono::NaiveDate` if the `chrono` feature is enabled
+/// and for `time::Date` if the `time` feature is enabled.
+pub trait DatepickerDate: Copy + 'static {
+    /// Create a date from year, month (1-12) and day (1-31). Returns `None` if the date doesn't exist.
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self>;
+
+    /// Returns year, month (1-12) and day (1-31) of the date.
+    fn to_ymd(&self) -> (i32, u32, u32);
+
+    /// Returns the day of the week, with `0` being Sunday and `6` being Saturday.
+    fn weekday_from_sunday(&self) -> u32;
+}
+
+#[cfg(feature = "chrono")]
+impl DatepickerDate for chrono::NaiveDate {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    fn to_ymd(&self) -> (i32, u32, u32) {
+        use chrono::Datelike;
+        (self.year(), self.month(), self.day())
+    }
+
+    fn weekday_from_sunday(&self) -> u32 {
+        use chrono::Datelike;
+        self.weekday().num_days_from_sunday()
+    }
+}
+
+#[cfg(feature = "time")]
+impl DatepickerDate for time::Date {
+    fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        let month = time::Month::try_from(u8::try_from(month).ok()?).ok()?;
+        time::Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+    }
+
+    fn to_ymd(&self) -> (i32, u32, u32) {
+        (
+            self.year(),
+            u32::from(u8::from(self.month())),
+            u32::from(self.day()),
+        )
+    }
+
+    fn weekday_from_sunday(&self) -> u32 {
+        u32::from(self.weekday().number_days_from_sunday())
+    }
+}
+
+/// A Datepicker component
+///
+/// The date type is generic, see [DatepickerDate]. Enable the `chrono` or `time` feature to use it with those crates.
+///
+/// See [Tailwind Elements: Datepicker](https://tailwind-elements.com/docs/standard/forms/datepicker/)
+#[component]
+pub fn Datepicker<D: DatepickerDate, OnChangeFn: Fn(Option<D>) + 'static>(
+    /// This signal is read to show the currently selected date.
+    /// Whenever this signal changes, the datepicker is updated.
+    /// But note that the date can also be changed by the user and if the `on_change` function doesn't
+    /// update the `value` signal, the datepicker can get out of sync with the signal.
+    #[prop(into)]
+    value: MaybeSignal<Option<D>>,
+    /// Callback that is called when the user selects a date, or with `None` when the user clears the date.
+    on_change: OnChangeFn,
+    /// Dates before this date can't be selected.
+    #[prop(into, default = None.into())]
+    min: MaybeSignal<Option<D>>,
+    /// Dates after this date can't be selected.
+    #[prop(into, default = None.into())]
+    max: MaybeSignal<Option<D>>,
+    /// Predicate deciding which dates can't be selected. Dates for which this returns `true` are disabled.
+    #[prop(into, optional)]
+    is_date_disabled: Option<Callback<D, bool>>,
+    /// How the date is shown in the input field and how typed input is parsed.
+    /// Supports `d`, `dd`, `ddd`, `dddd`, `m`, `mm`, `mmm`, `mmmm`, `yy`, and `yyyy`. Other characters are shown as they are.
+    ///
+    /// See [Tailwind Elements: Datepicker#Formats](https://tailwind-elements.com/docs/standard/forms/datepicker/#formats)
+    #[prop(into, default = "dd/mm/yyyy".into())]
+    format: MaybeSignal<String>,
+    /// If `true`, the calendar is shown in a dropdown below the input field. If `false`, it is shown in a modal.
+    #[prop(into, default = false.into())]
+    inline: MaybeSignal<bool>,
+    /// Whether the datepicker is disabled.
+    #[prop(into, default = false.into())]
+    disabled: MaybeSignal<bool>,
+    /// The id of the input field. Useful to associate a label with it.
+    #[prop(into, default = "".into())]
+    id: String,
+    /// This label is shown as a placeholder while the field is empty and it is shown as a label at the top of the input field when it is non-empty or has focus.
+    #[prop(into, default = "".into())]
+    label: MaybeSignal<String>,
+) -> impl IntoView {
+    let format = Signal::derive(move || format.get());
+    let on_change = store_value(on_change);
+    let is_date_disabled_stored = store_value(is_date_disabled.clone());
+    // Tailwind Elements doesn't check typed dates against `min`, `max` and `is_date_disabled`, so we do it here.
+    let is_selectable = move |date: D| {
+        is_date_in_range(&date, min.get_untracked(), max.get_untracked())
+            && !is_date_disabled_stored.with_value(|is_date_disabled| {
+                is_date_disabled
+                    .as_ref()
+                    .is_some_and(|is_date_disabled| is_date_disabled(date))
+            })
+    };
+    let on_input = move |text: String| {
+        if text.trim().is_empty() {
+            on_change.with_value(|on_change| on_change(None));
+        } else if let Some(date) = format
+            .with_untracked(|format| parse_date(format, &text))
+            .filter(|date| is_selectable(*date))
+        {
+            on_change.with_value(|on_change| on_change(Some(date)));
+        }
+        // Otherwise, the user is still typing and the input isn't a valid date yet, or the date can't be selected.
+    };
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Div> = create_node_ref();
+    let input_ref: NodeRef<Input> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let filter = is_date_disabled.clone().map(|is_date_disabled| {
+                Closure::<dyn FnMut(i32, u32, u32) -> bool>::new(move |year, month, day| {
+                    D::from_ymd(year, month, day)
+                        .map(|date| !is_date_disabled(date))
+                        .unwrap_or(false)
+                })
+            });
+            let options = JsDatepickerOptions {
+                format: format(),
+                min: min().map(|date| js_date_string(&date)),
+                max: max().map(|date| js_date_string(&date)),
+                filter: filter
+                    .as_ref()
+                    .map(te_datepicker_make_filter)
+                    .unwrap_or(JsValue::NULL),
+                inline: inline(),
+            };
+            let jsinput = JsInput::new(&element);
+            let jsdatepicker =
+                JsDatepicker::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
+
+            // Clearing the date doesn't trigger an input event, so we also check the input field when the datepicker closes.
+            let on_close: Closure<dyn FnMut()> = Closure::new(move || {
+                if let Some(input) = input_ref.get_untracked() {
+                    on_input(input.value());
+                }
+            });
+            let close_listener =
+                te_datepicker_add_event_listener(&element, "close.te.datepicker", &on_close);
+
+            on_cleanup(move || {
+                te_datepicker_remove_event_listener(
+                    &element,
+                    "close.te.datepicker",
+                    &close_listener,
+                );
+                jsdatepicker.dispose();
+                jsinput.dispose();
+                std::mem::drop(on_close);
+                std::mem::drop(filter);
+            });
+        }
+    });
+
+    let id = if id.is_empty() { None } else { Some(id) };
+    let text = move || {
+        value()
+            .map(|date| format.with(|format| format_date(format, &date)))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <div ref=element_ref class="relative mb-3">
+            <input
+                ref=input_ref
+                type="text"
+                class="peer block min-h-[auto] w-full rounded border-0 bg-transparent px-3 py-[0.32rem] leading-[1.6] outline-none transition-all duration-200 ease-linear focus:placeholder:opacity-100 peer-focus:text-primary data-[te-input-state-active]:placeholder:opacity-100 motion-reduce:transition-none dark:text-neutral-200 dark:placeholder:text-neutral-200 dark:peer-focus:text-primary [&:not([data-te-input-placeholder-active])]:placeholder:opacity-0"
+                placeholder=label.clone()
+                aria-label=label.clone()
+                id=id.clone()
+                disabled=disabled
+                prop:value=text
+                on:input=move |ev| on_input(event_target_value(&ev))
+            />
+            <label
+                for=id
+                class="pointer-events-none absolute left-3 top-0 mb-0 max-w-[90%] origin-[0_0] truncate pt-[0.37rem] leading-[1.6] text-neutral-500 transition-all duration-200 ease-out peer-focus:-translate-y-[0.9rem] peer-focus:scale-[0.8] peer-focus:text-primary peer-data-[te-input-state-active]:-translate-y-[0.9rem] peer-data-[te-input-state-active]:scale-[0.8] motion-reduce:transition-none dark:text-neutral-200 dark:peer-focus:text-primary"
+            >
+                {label}
+            </label>
+        </div>
+    }
+}
+
+// These are the defaults of Tailwind Elements, which is what the datepicker shows.
+const MONTHS_FULL: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const MONTHS_SHORT: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const WEEKDAYS_FULL: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const WEEKDAYS_SHORT: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatToken {
+    Day,
+    DayPadded,
+    WeekdayShort,
+    WeekdayFull,
+    Month,
+    MonthPadded,
+    MonthShort,
+    MonthFull,
+    YearShort,
+    Year,
+    Literal(char),
+}
+
+fn tokenize_format(format: &str) -> Vec<FormatToken> {
+    let chars: Vec<char> = format.chars().collect();
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        let run = chars[pos..].iter().take_while(|&&other| other == c).count();
+        let (token, len) = match (c, run) {
+            ('!', _) if pos + 1 < chars.len() => (FormatToken::Literal(chars[pos + 1]), 2),
+            ('d', 1) => (FormatToken::Day, 1),
+            ('d', 2) => (FormatToken::DayPadded, 2),
+            ('d', 3) => (FormatToken::WeekdayShort, 3),
+            ('d', _) => (FormatToken::WeekdayFull, 4),
+            ('m', 1) => (FormatToken::Month, 1),
+            ('m', 2) => (FormatToken::MonthPadded, 2),
+            ('m', 3) => (FormatToken::MonthShort, 3),
+            ('m', _) => (FormatToken::MonthFull, 4),
+            ('y', 2 | 3) => (FormatToken::YearShort, 2),
+            ('y', run) if run >= 4 => (FormatToken::Year, 4),
+            (c, _) => (FormatToken::Literal(c), 1),
+        };
+        tokens.push(token);
+        pos += len;
+    }
+    tokens
+}
+
+pub(super) fn format_date<D: DatepickerDate>(format: &str, date: &D) -> String {
+    let (year, month, day) = date.to_ymd();
+    let month_index = (month - 1) as usize;
+    let weekday_index = date.weekday_from_sunday() as usize;
+    tokenize_format(format)
+        .into_iter()
+        .map(|token| match token {
+            FormatToken::Day => day.to_string(),
+            FormatToken::DayPadded => format!("{day:02}"),
+            FormatToken::WeekdayShort => WEEKDAYS_SHORT[weekday_index].to_string(),
+            FormatToken::WeekdayFull => WEEKDAYS_FULL[weekday_index].to_string(),
+            FormatToken::Month => month.to_string(),
+            FormatToken::MonthPadded => format!("{month:02}"),
+            FormatToken::MonthShort => MONTHS_SHORT[month_index].to_string(),
+            FormatToken::MonthFull => MONTHS_FULL[month_index].to_string(),
+            FormatToken::YearShort => format!("{:02}", year.rem_euclid(100)),
+            FormatToken::Year => format!("{year:04}"),
+            FormatToken::Literal(c) => c.to_string(),
+        })
+        .collect()
+}
+
+pub(super) fn parse_date<D: DatepickerDate>(format: &str, input: &str) -> Option<D> {
+    let mut rest = input.trim();
+    let mut year = None;
+    let mut month = None;
+    let mut day = None;
+    for token in tokenize_format(format) {
+        match token {
+            FormatToken::Day | FormatToken::DayPadded => {
+                day = Some(parse_number(&mut rest, 2)?);
+            }
+            FormatToken::Month | FormatToken::MonthPadded => {
+                month = Some(parse_number(&mut rest, 2)?);
+            }
+            FormatToken::YearShort => {
+                year = Some(2000 + parse_number(&mut rest, 2)? as i32);
+            }
+            FormatToken::Year => {
+                year = Some(parse_number(&mut rest, 4)? as i32);
+            }
+            FormatToken::MonthShort => {
+                month = Some(parse_name(&mut rest, &MONTHS_SHORT)? + 1);
+            }
+            FormatToken::MonthFull => {
+                month = Some(parse_name(&mut rest, &MONTHS_FULL)? + 1);
+            }
+            // The weekday follows from the date, so we only need to skip over it.
+            FormatToken::WeekdayShort => {
+                parse_name(&mut rest, &WEEKDAYS_SHORT)?;
+            }
+            FormatToken::WeekdayFull => {
+                parse_name(&mut rest, &WEEKDAYS_FULL)?;
+            }
+            FormatToken::Literal(c) => {
+                rest = rest.strip_prefix(c)?;
+            }
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+    D::from_ymd(year?, month?, day?)
+}
+
+fn parse_number(input: &mut &str, max_digits: usize) -> Option<u32> {
+    let num_digits = input
+        .chars()
+        .take(max_digits)
+        .take_while(char::is_ascii_digit)
+        .count();
+    if num_digits == 0 {
+        return None;
+    }
+    let (digits, rest) = input.split_at(num_digits);
+    *input = rest;
+    digits.parse().ok()
+}
+
+fn parse_name(input: &mut &str, names: &[&str]) -> Option<u32> {
+    let (index, name) = names.iter().enumerate().find(|(_, name)| {
+        input
+            .get(..name.len())
+            .map(|prefix| prefix.eq_ignore_ascii_case(name))
+            .unwrap_or(false)
+    })?;
+    *input = &input[name.len()..];
+    Some(index as u32)
+}
+
+// Whether the date is between `min` and `max`, inclusive.
+pub(super) fn is_date_in_range<D: DatepickerDate>(
+    date: &D,
+    min: Option<D>,
+    max: Option<D>,
+) -> bool {
+    let date = date.to_ymd();
+    min.map_or(true, |min| min.to_ymd() <= date) && max.map_or(true, |max| date <= max.to_ymd())
+}
+
+pub(super) fn js_date_string<D: DatepickerDate>(date: &D) -> String {
+    let (year, month, day) = date.to_ymd();
+    // Without a timezone suffix, JavaScript interprets this as local time, which is what the datepicker compares against.
+    format!("{year:04}-{month:02}-{day:02}T00:00:00")
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Datepicker)]
+    type JsDatepicker;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Datepicker, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsDatepicker;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Datepicker, final)]
+    fn dispose(this: &JsDatepicker);
+}
+
+#[wasm_bindgen(inline_js = "
+    export function te_datepicker_add_event_listener(datepicker_html_elem, event_name, callback) {
+        const listener = (event) => {
+            callback();
+        };
+        datepicker_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_datepicker_remove_event_listener(datepicker_html_elem, event_name, listener) {
+        datepicker_html_elem.removeEventListener(event_name, listener);
+    }
+    export function te_datepicker_make_filter(callback) {
+        return (date) => callback(date.getFullYear(), date.getMonth() + 1, date.getDate());
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_datepicker_add_event_listener(
+        datepicker: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_datepicker_remove_event_listener(
+        datepicker: &web_sys::HtmlElement,
+        event_name: &str,
+        listener: &JsValue,
+    );
+
+    #[wasm_bindgen]
+    fn te_datepicker_make_filter(callback: &Closure<dyn FnMut(i32, u32, u32) -> bool>) -> JsValue;
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct JsDatepickerOptions {
+    pub(super) format: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) min: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) max: Option<String>,
+
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    pub(super) filter: JsValue,
+
+    pub(super) inline: bool,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/forms/datepicker/#docsTabsAPI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize() {
+        use FormatToken::*;
+        assert_eq!(
+            vec![DayPadded, Literal('/'), MonthPadded, Literal('/'), Year],
+            tokenize_format("dd/mm/yyyy")
+        );
+        assert_eq!(
+            vec![
+                WeekdayShort,
+                Literal(','),
+                Literal(' '),
+                Day,
+                Literal(' '),
+                MonthFull,
+                Literal(' '),
+                YearShort
+            ],
+            tokenize_format("ddd, d mmmm yy")
+        );
+        assert_eq!(
+            vec![WeekdayFull, MonthFull, Month],
+            tokenize_format("ddddmmmmm")
+        );
+        assert_eq!(
+            vec![Literal('d'), Literal('y'), Literal(' '), Day],
+            tokenize_format("!d!y d")
+        );
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    mod generic {
+        use super::super::*;
+        use std::fmt::Debug;
+
+        const FORMATS: &[&str] = &[
+            "dd/mm/yyyy",
+            "d/m/yyyy",
+            "dd.mm.yyyy",
+            "yyyy-mm-dd",
+            "mm-dd-yy",
+            "ddd, dd mmm yyyy",
+            "dddd, d mmmm yyyy",
+            "mmmm d, yyyy",
+            "!d!a!y: dd mm yyyy",
+        ];
+
+        pub fn round_trip<D: DatepickerDate + Debug + PartialEq>() {
+            // Two digit years are parsed as 20xx, so only use dates in that century
+            for (year, month, day) in [(2000, 1, 1), (2023, 10, 5), (2024, 2, 29), (2099, 12, 31)] {
+                let date = D::from_ymd(year, month, day).unwrap();
+                for format in FORMATS {
+                    let text = format_date(format, &date);
+                    assert_eq!(
+                        Some(date),
+                        parse_date::<D>(format, &text),
+                        "{format:?}: {text:?}"
+                    );
+                }
+            }
+        }
+
+        pub fn format<D: DatepickerDate>() {
+            let date = D::from_ymd(2023, 10, 5).unwrap();
+            assert_eq!("05/10/2023", format_date("dd/mm/yyyy", &date));
+            assert_eq!("5/10/2023", format_date("d/m/yyyy", &date));
+            assert_eq!("10-05-23", format_date("mm-dd-yy", &date));
+            assert_eq!("Thu, 05 Oct 2023", format_date("ddd, dd mmm yyyy", &date));
+            assert_eq!(
+                "Thursday, 5 October 2023",
+                format_date("dddd, d mmmm yyyy", &date)
+            );
+            assert_eq!("day: 05 10 2023", format_date("!d!a!y: dd mm yyyy", &date));
+            assert_eq!("2023-10-05T00:00:00", js_date_string(&date));
+        }
+
+        pub fn parse<D: DatepickerDate + Debug + PartialEq>() {
+            let date = D::from_ymd(2023, 10, 5);
+            assert_eq!(date, parse_date::<D>("dd/mm/yyyy", " 05/10/2023 "));
+            assert_eq!(date, parse_date::<D>("dd/mm/yyyy", "5/10/2023"));
+            assert_eq!(
+                date,
+                parse_date::<D>("ddd, dd mmm yyyy", "thu, 05 OCT 2023")
+            );
+            assert_eq!(None, parse_date::<D>("dd/mm/yyyy", ""));
+            assert_eq!(None, parse_date::<D>("dd/mm/yyyy", "05/10"));
+            assert_eq!(None, parse_date::<D>("dd/mm/yyyy", "05/10/2023 extra"));
+            assert_eq!(None, parse_date::<D>("dd/mm/yyyy", "05-10-2023"));
+            assert_eq!(None, parse_date::<D>("dd/mm/yyyy", "31/02/2023"));
+            assert_eq!(None, parse_date::<D>("dd/mm/yyyy", "05/13/2023"));
+            assert_eq!(
+                None,
+                parse_date::<D>("ddd, dd mmm yyyy", "Thu, 05 Okt 2023")
+            );
+        }
+
+        pub fn in_range<D: DatepickerDate>() {
+            let date = |year, month, day| D::from_ymd(year, month, day).unwrap();
+            let min = Some(date(2023, 1, 1));
+            let max = Some(date(2023, 12, 31));
+            assert!(is_date_in_range(&date(2023, 6, 15), min, max));
+            assert!(is_date_in_range(&date(2023, 1, 1), min, max));
+            assert!(is_date_in_range(&date(2023, 12, 31), min, max));
+            assert!(!is_date_in_range(&date(1990, 1, 1), min, max));
+            assert!(!is_date_in_range(&date(2022, 12, 31), min, max));
+            assert!(!is_date_in_range(&date(2024, 1, 1), min, max));
+            let single_day = Some(date(2023, 1, 31));
+            assert!(is_date_in_range(&date(2023, 1, 31), single_day, single_day));
+            assert!(!is_date_in_range(&date(2023, 2, 1), single_day, single_day));
+            assert!(is_date_in_range(&date(1990, 1, 1), None, max));
+            assert!(is_date_in_range(&date(2099, 1, 1), min, None));
+            assert!(is_date_in_range(&date(2099, 1, 1), None, None));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono {
+        use super::generic;
+
+        #[test]
+        fn round_trip() {
+            generic::round_trip::<::chrono::NaiveDate>();
+        }
+
+        #[test]
+        fn format() {
+            generic::format::<::chrono::NaiveDate>();
+        }
+
+        #[test]
+        fn parse() {
+            generic::parse::<::chrono::NaiveDate>();
+        }
+
+        #[test]
+        fn in_range() {
+            generic::in_range::<::chrono::NaiveDate>();
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time {
+        use super::generic;
+
+        #[test]
+        fn round_trip() {
+            generic::round_trip::<::time::Date>();
+        }
+
+        #[test]
+        fn format() {
+            generic::format::<::time::Date>();
+        }
+
+        #[test]
+        fn parse() {
+            generic::parse::<::time::Date>();
+        }
+
+        #[test]
+        fn in_range() {
+            generic::in_range::<::time::Date>();
+        }
+    }
+}
@@ -1,7 +1,8 @@
-use leptos::{html::Div, *};
+use leptos::{ev::KeyboardEvent, html::Div, *};
 use wasm_bindgen::prelude::wasm_bindgen;
 use web_sys::HtmlDivElement;
 
+use super::validation::{FieldFeedback, FieldState, Rule};
 use crate::utils::{MaybeSignalExt, SignalBoolExt};
 
 /// The type of an input field. This influences behavior of the input field and how its content is validated.
@@ -81,6 +82,24 @@ pub fn Input<OnChangeFn: Fn(String) + 'static>(
     /// Display a unit symbol in the input field
     #[prop(into, default = "".to_string().into())]
     unit: MaybeSignal<String>,
+    /// Callback that is called when a key is pressed in the input field.
+    #[prop(optional, into)]
+    on_keydown: Option<Callback<KeyboardEvent>>,
+    /// Callback that is called when the input field gets the focus.
+    #[prop(optional, into)]
+    on_focus: Option<Callback<()>>,
+    /// Callback that is called when the input field loses the focus.
+    #[prop(optional, into)]
+    on_blur: Option<Callback<()>>,
+    /// The name of the input field. Inside of a [Form](super::Form), the field is validated if it has a name.
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// The rules the content of the input field is validated with, if it is inside of a [Form](super::Form). See [Rule].
+    #[prop(optional)]
+    rules: Vec<Rule>,
+    /// Additional attributes for the `<input>` element, e.g. ARIA attributes. Pass them with `attr:name=value`.
+    #[prop(attrs)]
+    attributes: Vec<(&'static str, Attribute)>,
 ) -> impl IntoView {
     let class = move || {
         let mut class = "peer block min-h-[auto] w-full rounded border-0 px-3 py-[0.32rem] leading-[1.6] outline-none transition-all duration-200 ease-linear focus:placeholder:opacity-100 peer-focus:text-primary data-[te-input-state-active]:placeholder:opacity-100 motion-reduce:transition-none dark:text-neutral-200 dark:placeholder:text-neutral-200 dark:peer-focus:text-primary [&:not([data-te-input-placeholder-active])]:placeholder:opacity-0".to_string();
@@ -101,18 +120,37 @@ pub fn Input<OnChangeFn: Fn(String) + 'static>(
         }
     });
 
+    let field = {
+        let value = value.clone();
+        FieldState::register(
+            name.clone(),
+            Signal::derive(move || value.get()),
+            |value: &String| vec![value.clone()],
+            rules,
+        )
+    };
+    let validity = FieldState::validity(field);
+
     let id = if id.is_empty() { None } else { Some(id) };
 
     let unit = store_value(unit);
 
     view! {
-        <div ref=element_ref class="relative mb-3">
+        // The validation feedback is shown below the input field, so it needs more space then
+        <div
+            ref=element_ref
+            class="relative"
+            class=("mb-3", move || validity().is_none())
+            class=("mb-8", move || validity().is_some())
+        >
             <input
                 type=input_type.map(InputType::html_attrib)
                 class=class
                 placeholder=label.clone()
                 aria-label=label.clone()
                 id=id.clone()
+                name=name
+                aria-invalid=move || (validity() == Some(false)).then_some("true")
                 disabled=disabled
                 readonly=readonly
                 min=move || if input_type() == InputType::Number { Some(min().to_string()) } else { None }
@@ -121,10 +159,28 @@ pub fn Input<OnChangeFn: Fn(String) + 'static>(
                 on:input=move |ev| {
                     on_change(event_target_value(&ev));
                 }
+                on:keydown=move |ev| {
+                    if let Some(on_keydown) = &on_keydown {
+                        on_keydown(ev);
+                    }
+                }
+                on:focus=move |_| {
+                    if let Some(on_focus) = &on_focus {
+                        on_focus(());
+                    }
+                }
+                on:blur=move |_| {
+                    if let Some(on_blur) = &on_blur {
+                        on_blur(());
+                    }
+                }
+                {..attributes}
             />
             <label
                 for=id
                 class="pointer-events-none absolute left-3 top-0 mb-0 max-w-[90%] origin-[0_0] truncate pt-[0.37rem] leading-[1.6] text-neutral-500 transition-all duration-200 ease-out peer-focus:-translate-y-[0.9rem] peer-focus:scale-[0.8] peer-focus:text-primary peer-data-[te-input-state-active]:-translate-y-[0.9rem] peer-data-[te-input-state-active]:scale-[0.8] motion-reduce:transition-none dark:text-neutral-200 dark:peer-focus:text-primary"
+                class=("!text-[#14a44d]", move || validity() == Some(true))
+                class=("!text-[#dc4c64]", move || validity() == Some(false))
             >
                 {label}
             </label>
@@ -132,6 +188,7 @@ pub fn Input<OnChangeFn: Fn(String) + 'static>(
                 // TODO Would be nicer to show the unit appended to the input text instead of at the right border of the field
                 <span class="pointer-events-none absolute right-8 top-0 mb-0 max-w-[90%] pt-[0.37rem] leading-[1.6] text-neutral-500 dark:text-neutral-200">{unit}</span>
             </Show>
+            <FieldFeedback state=field />
         </div>
     }
 }
@@ -139,12 +196,12 @@ pub fn Input<OnChangeFn: Fn(String) + 'static>(
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = te, js_name = Input)]
-    type JsInput;
+    pub(super) type JsInput;
 
     // TODO Input constructor can take some options, see https://tailwind-elements.com/docs/standard/forms/inputs/#docsTabsAPI
     #[wasm_bindgen(constructor, js_namespace = te, js_class = Input, final)]
-    fn new(e: &HtmlDivElement) -> JsInput;
+    pub(super) fn new(e: &HtmlDivElement) -> JsInput;
 
     #[wasm_bindgen(method, js_namespace = te, js_class = Input, final)]
-    fn dispose(this: &JsInput);
+    pub(super) fn dispose(this: &JsInput);
 }
@@ -9,4 +9,24 @@ mod switch;
 pub use switch::Switch;
 
 mod select;
-pub use select::{Select, SelectOption};
+/// Derive `SelectOption` for an enum. The derived values are guaranteed to be unique.
+pub use leptos_twelements_macros::SelectOption;
+pub use select::{MultiSelect, Select, SelectError, SelectOption};
+
+mod validation;
+pub use validation::{use_form, Form, FormContext, FormValues, Rule};
+
+mod autocomplete;
+pub use autocomplete::Autocomplete;
+
+mod rating;
+pub use rating::{Rating, RatingIcon};
+
+mod datepicker;
+pub use datepicker::{Datepicker, DatepickerDate};
+
+mod timepicker;
+pub use timepicker::{Timepicker, TimepickerFormat, TimepickerTime};
+
+mod date_timepicker;
+pub use date_timepicker::{DateTimepicker, DateTimepickerDateTime};
@@ -0,0 +1,193 @@
+use leptos::{html::Ul, *};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+use crate::components::TooltipPlacement;
+
+/// A custom icon for a [Rating]. Pass it to the rating with `<RatingIcon slot>`.
+///
+/// The icon must contain an `<svg>` element. Tailwind Elements adds `fill-current` to it for active stars.
+#[slot]
+pub struct RatingIcon {
+    /// The icon, rendered once for each star
+    children: ChildrenFn,
+}
+
+/// A Rating component
+///
+/// See [Tailwind Elements: Rating](https://tailwind-elements.com/docs/standard/forms/rating/)
+#[component]
+pub fn Rating<OnChangeFn: Fn(u8) + 'static>(
+    /// This signal is read to show the current rating, `0` means no star is selected.
+    /// Whenever this signal changes, the rating is updated.
+    /// But note that the rating can also be changed by the user and if the `on_change` function doesn't
+    /// update the `value` signal, the rating can get out of sync with the signal.
+    #[prop(into)]
+    value: MaybeSignal<u8>,
+    /// Callback that is called when the user selects a rating.
+    on_change: OnChangeFn,
+    /// Callback that is called with the hovered rating when the user moves the mouse over a star.
+    #[prop(into, optional)]
+    on_hover: Option<Callback<u8>>,
+    /// The number of stars.
+    #[prop(default = 5)]
+    max: u8,
+    /// Whether the rating is readonly, i.e. only shows the value but can't be changed by the user.
+    #[prop(into, default = false.into())]
+    readonly: MaybeSignal<bool>,
+    /// Tooltips shown when hovering the stars. The first entry is for the first star, and so on.
+    /// Stars without an entry don't get a tooltip.
+    #[prop(into, default = vec![])]
+    tooltips: Vec<String>,
+    /// Where the tooltips are shown relative to the stars. See [TooltipPlacement].
+    #[prop(default = TooltipPlacement::Top)]
+    tooltip_placement: TooltipPlacement,
+    /// A custom icon to use instead of the default star. See [RatingIcon].
+    #[prop(optional)]
+    rating_icon: Option<RatingIcon>,
+) -> impl IntoView {
+    let on_change = Rc::new(on_change);
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Ul> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let options = JsRatingOptions {
+                value: value(),
+                readonly: readonly(),
+                tooltip: tooltip_placement.js_value().to_string(),
+            };
+            // Tailwind Elements only sets the active stars for a nonzero value, so clear any left over from a previous instance.
+            te_rating_clear(&element);
+            let jsrating = JsRating::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
+
+            let on_change = Rc::clone(&on_change);
+            let on_select: Closure<dyn FnMut(u8)> = Closure::new(move |value| on_change(value));
+            let on_hover = on_hover.clone();
+            let on_hover: Closure<dyn FnMut(u8)> = Closure::new(move |value| {
+                if let Some(on_hover) = &on_hover {
+                    on_hover(value);
+                }
+            });
+            // The element outlives this instance if the options change, so the listeners must be removed again on cleanup.
+            let on_select_listener =
+                te_rating_add_event_listener(&element, "onSelect.te.rating", &on_select);
+            let on_hover_listener =
+                te_rating_add_event_listener(&element, "onHover.te.rating", &on_hover);
+
+            on_cleanup(move || {
+                te_rating_remove_event_listener(
+                    &element,
+                    "onSelect.te.rating",
+                    &on_select_listener,
+                );
+                te_rating_remove_event_listener(&element, "onHover.te.rating", &on_hover_listener);
+                // Tailwind Elements throws at the very end of dispose() for non-readonly ratings, after everything was cleaned up.
+                let _ = jsrating.dispose();
+                std::mem::drop(on_select);
+                std::mem::drop(on_hover);
+            });
+        }
+    });
+
+    let icon = move || match &rating_icon {
+        Some(rating_icon) => (rating_icon.children)().into_view(),
+        None => view! { <StarIcon /> }.into_view(),
+    };
+
+    view! {
+        <ul ref=element_ref class="my-1 flex list-none gap-1 p-0">
+            {(0..usize::from(max)).map(|index| view! {
+                <li>
+                    <span
+                        class="text-primary [&>svg]:h-5 [&>svg]:w-5"
+                        data-te-rating-icon-ref
+                        title=tooltips.get(index).cloned()
+                    >
+                        {icon()}
+                    </span>
+                </li>
+            }).collect_view()}
+        </ul>
+    }
+}
+
+#[component]
+fn StarIcon() -> impl IntoView {
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            fill="none"
+            viewBox="0 0 24 24"
+            stroke-width="1.5"
+            stroke="currentColor">
+            <path
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                d="M11.48 3.499a.562.562 0 011.04 0l2.125 5.111a.563.563 0 00.475.345l5.518.442c.499.04.701.663.321.988l-4.204 3.602a.563.563 0 00-.182.557l1.285 5.385a.562.562 0 01-.84.61l-4.725-2.885a.563.563 0 00-.586 0L6.982 20.54a.562.562 0 01-.84-.61l1.285-5.386a.562.562 0 00-.182-.557l-4.204-3.602a.563.563 0 01.321-.988l5.518-.442a.563.563 0 00.475-.345L11.48 3.5z" />
+        </svg>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Rating)]
+    type JsRating;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Rating, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsRating;
+
+    #[wasm_bindgen(method, catch, js_namespace = te, js_class = Rating, final)]
+    fn dispose(this: &JsRating) -> Result<(), JsValue>;
+}
+
+#[wasm_bindgen(inline_js = "
+    export function te_rating_add_event_listener(rating_html_elem, event_name, callback) {
+        const listener = (event) => {
+            callback(event.value);
+        };
+        rating_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_rating_remove_event_listener(rating_html_elem, event_name, listener) {
+        rating_html_elem.removeEventListener(event_name, listener);
+    }
+    export function te_rating_clear(rating_html_elem) {
+        rating_html_elem.querySelectorAll('[data-te-rating-icon-ref] svg').forEach((svg) => {
+            svg.classList.remove('fill-current');
+        });
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_rating_add_event_listener(
+        rating: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut(u8)>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_rating_remove_event_listener(
+        rating: &web_sys::HtmlElement,
+        event_name: &str,
+        listener: &JsValue,
+    );
+
+    #[wasm_bindgen]
+    fn te_rating_clear(rating: &web_sys::HtmlElement);
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsRatingOptions {
+    value: u8,
+
+    readonly: bool,
+
+    tooltip: String,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/forms/rating/#docsTabsAPI
+}
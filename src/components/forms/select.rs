@@ -1,20 +1,95 @@
 use leptos::*;
-use std::sync::Arc;
-use wasm_bindgen::{closure::Closure, prelude::wasm_bindgen};
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
 use web_sys::HtmlSelectElement;
 
+use super::validation::{FieldFeedback, FieldState, Rule};
+
 // TODO More flexibility, implement remaining tailwind elements features
 
 /// Implement this for a type, e.g. an enum, to make this type usable as an option in a select input.
+///
+/// For enums, this can be derived with `#[derive(SelectOption)]`, which also guarantees that the values are unique.
+///
+/// Example
+/// -------
+/// ```
+/// use leptos_twelements::components::forms::SelectOption;
+///
+/// #[derive(Clone, SelectOption)]
+/// enum Color {
+///     #[select_option(value = "r", label = "Red")]
+///     Red,
+///     #[select_option(value = "g", label = "Green")]
+///     Green,
+/// }
+///
+/// assert_eq!("g", Color::Green.value());
+/// ```
+///
+/// Two variants with the same value don't compile:
+/// ```compile_fail
+/// use leptos_twelements::components::forms::SelectOption;
+///
+/// #[derive(Clone, SelectOption)]
+/// enum Color {
+///     #[select_option(value = "r", label = "Red")]
+///     Red,
+///     #[select_option(value = "r", label = "Rose")]
+///     Rose,
+/// }
+/// ```
 pub trait SelectOption {
     /// A string representation of the option value, used in the `value` attribute of the `<option>` tag.
-    /// These must be unique and uniquely identify the option.
+    /// These must be unique, non-empty and uniquely identify the option.
     fn value(&self) -> String;
     /// How to display the option in the select input.
     fn view(&self) -> View;
+    /// The label of the group the option is shown in. Options without a group are shown at the top level.
+    /// Options of the same group are shown together at the position of the first one.
+    fn group(&self) -> Option<String> {
+        None
+    }
+    /// Whether the option is shown but can't be selected.
+    fn disabled(&self) -> bool {
+        false
+    }
+    /// A second line of text shown below the option in the dropdown.
+    fn secondary_text(&self) -> Option<String> {
+        None
+    }
+    /// The URL of an image shown next to the option in the dropdown.
+    fn icon(&self) -> Option<String> {
+        None
+    }
 }
 
-/// A Select component
+/// A Select component, i.e. an input to choose one of a list of options.
+///
+/// **Breaking change in 0.0.8:** `value` is now a `Signal<Option<O>>` and `on_change` is called with an `Option<O>`,
+/// where `None` means that nothing is selected, e.g. after clicking the clear button. To migrate, pass
+/// `value=Signal::derive(move || Some(value()))` and ignore `None` in `on_change` if the select can't be cleared.
+///
+/// Example
+/// -------
+/// ```ignore
+/// let (color, set_color) = create_signal(None);
+/// view! {
+///     <Select
+///         label="Color"
+///         options=vec![Color::Red, Color::Green, Color::Blue]
+///         value=color
+///         on_change=move |color| set_color(color)
+///         placeholder="Choose a color"
+///         clear_button=true
+///     />
+/// }
+/// ```
 ///
 /// See [Tailwind Elements: Select](https://tailwind-elements.com/docs/standard/forms/select/)
 #[component]
@@ -23,77 +98,383 @@ pub fn Select<O, OnChangeFn>(
     // TODO Auto-assign id
     #[prop(into, default = "".into())]
     id: String,
-    #[prop(into)] label: String,
-    // TODO Make options reactive?
-    #[prop(into)] options: Vec<O>,
-    /// Set the current value
+    /// The label of the select input.
     #[prop(into)]
-    value: Signal<O>,
+    label: String,
+    /// The options to choose from. Whenever this signal changes, the options are re-rendered.
+    #[prop(into)]
+    options: MaybeSignal<Vec<O>>,
+    /// Set the current value. `None` means that no option is selected and the placeholder is shown.
+    #[prop(into)]
+    value: Signal<Option<O>>,
     /// Callback that is called when the selection is changed.
+    /// It is called with `None` when the selection is removed with the clear button.
+    on_change: OnChangeFn,
+    /// Whether to show a search box in the dropdown to filter the options.
+    #[prop(default = false)]
+    filter: bool,
+    /// Whether to show a button that removes the selection.
+    #[prop(default = false)]
+    clear_button: bool,
+    /// The text shown when no option is selected.
+    #[prop(into, default = "".into())]
+    placeholder: String,
+    /// Callback that is called when the options or the value reported by Tailwind Elements are invalid.
+    /// If not set, these errors are logged to the console, and duplicate values also panic in debug builds. See [SelectError].
+    #[prop(optional, into)]
+    on_error: Option<Callback<SelectError>>,
+    /// The name of the select input. Inside of a [Form](super::Form), the select input is validated if it has a name.
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// The rules the value is validated with, if the select input is inside of a [Form](super::Form). See [Rule].
+    #[prop(optional)]
+    rules: Vec<Rule>,
+) -> impl IntoView
+where
+    O: SelectOption + Clone + 'static,
+    OnChangeFn: Fn(Option<O>) + 'static,
+{
+    let options_config = JsSelectOptions {
+        multiple: false,
+        select_filter: filter,
+        select_clear_button: clear_button,
+        select_placeholder: placeholder,
+        select_all: false,
+        select_all_label: "".to_string(),
+    };
+    let field = FieldState::register(
+        name.clone(),
+        value,
+        |value: &Option<O>| value.iter().map(SelectOption::value).collect(),
+        rules,
+    );
+    view! {
+        <SelectImpl
+            id=id
+            label=label
+            options=Signal::derive(move || options.get())
+            values=Signal::derive(move || value().into_iter().collect())
+            on_change=Rc::new(move |values: Vec<O>| on_change(values.into_iter().next()))
+            options_config=options_config
+            on_error=on_error
+            name=name
+            field=field
+        />
+    }
+}
+
+/// A Select component that allows choosing multiple options. The options are shown with checkboxes.
+///
+/// Example
+/// -------
+/// ```ignore
+/// let (colors, set_colors) = create_signal(vec![]);
+/// view! {
+///     <MultiSelect
+///         label="Colors"
+///         options=vec![Color::Red, Color::Green, Color::Blue]
+///         value=colors
+///         on_change=move |colors| set_colors(colors)
+///         filter=true
+///     />
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Select#Multiselect](https://tailwind-elements.com/docs/standard/forms/select/#multiselect)
+#[component]
+pub fn MultiSelect<O, OnChangeFn>(
+    /// The id of the select input. Useful to associate a label with it.
+    // TODO Auto-assign id
+    #[prop(into, default = "".into())]
+    id: String,
+    /// The label of the select input.
+    #[prop(into)]
+    label: String,
+    /// The options to choose from. Whenever this signal changes, the options are re-rendered.
+    #[prop(into)]
+    options: MaybeSignal<Vec<O>>,
+    /// Set the currently selected options.
+    #[prop(into)]
+    value: Signal<Vec<O>>,
+    /// Callback that is called with all selected options when the selection is changed.
     on_change: OnChangeFn,
+    /// Whether to show a search box in the dropdown to filter the options.
+    #[prop(default = false)]
+    filter: bool,
+    /// Whether to show a button that removes the selection.
+    #[prop(default = false)]
+    clear_button: bool,
+    /// The text shown when no option is selected.
+    #[prop(into, default = "".into())]
+    placeholder: String,
+    /// Whether to show an option that selects all options.
+    #[prop(default = true)]
+    select_all: bool,
+    /// The text of the option that selects all options.
+    #[prop(into, default = "Select all".into())]
+    select_all_label: String,
+    /// Callback that is called when the options or the values reported by Tailwind Elements are invalid.
+    /// If not set, these errors are logged to the console, and duplicate values also panic in debug builds. See [SelectError].
+    #[prop(optional, into)]
+    on_error: Option<Callback<SelectError>>,
+    /// The name of the select input. Inside of a [Form](super::Form), the select input is validated if it has a name.
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// The rules the value is validated with, if the select input is inside of a [Form](super::Form). See [Rule].
+    #[prop(optional)]
+    rules: Vec<Rule>,
+) -> impl IntoView
+where
+    O: SelectOption + Clone + 'static,
+    OnChangeFn: Fn(Vec<O>) + 'static,
+{
+    let options_config = JsSelectOptions {
+        multiple: true,
+        select_filter: filter,
+        select_clear_button: clear_button,
+        select_placeholder: placeholder,
+        select_all,
+        select_all_label,
+    };
+    let field = FieldState::register(
+        name.clone(),
+        value,
+        |value: &Vec<O>| value.iter().map(SelectOption::value).collect(),
+        rules,
+    );
+    view! {
+        <SelectImpl
+            id=id
+            label=label
+            options=Signal::derive(move || options.get())
+            values=value
+            on_change=Rc::new(on_change)
+            options_config=options_config
+            on_error=on_error
+            name=name
+            field=field
+        />
+    }
+}
+
+#[component]
+fn SelectImpl<O>(
+    id: String,
+    label: String,
+    options: Signal<Vec<O>>,
+    values: Signal<Vec<O>>,
+    on_change: Rc<dyn Fn(Vec<O>)>,
+    options_config: JsSelectOptions,
+    on_error: Option<Callback<SelectError>>,
+    name: Option<String>,
+    field: Option<FieldState>,
 ) -> impl IntoView
 where
     O: SelectOption + Clone + 'static,
-    OnChangeFn: Fn(O) + Clone + 'static,
 {
+    let on_error = store_value(on_error);
+    let report_error = move |error: SelectError| {
+        on_error.with_value(|on_error| match on_error {
+            Some(on_error) => on_error(error),
+            None => logging::error!("{error}"),
+        })
+    };
+
+    create_effect(move |_| {
+        if let Some(value) = options.with(|options| find_duplicate_value(options)) {
+            let error = SelectError::DuplicateValue(value);
+            // Duplicate values are a bug in the caller, so fail loudly in debug builds unless the caller handles the error
+            let handled = on_error.with_value(Option::is_some);
+            report_error(error.clone());
+            debug_assert!(handled, "{error}");
+        }
+    });
+
+    let multiple = options_config.multiple;
+    let selected_values = create_memo(move |_| {
+        values.with(|values| values.iter().map(SelectOption::value).collect::<Vec<_>>())
+    });
+    let validity = FieldState::validity(field);
+
     // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
     let element_ref: NodeRef<leptos::html::Select> = create_node_ref();
-    let options = store_value(options);
+    let options_config = store_value(options_config);
+    let on_change = store_value(on_change);
     create_effect(move |_| {
+        // Tailwind Elements renders the options when it is initialized, so we have to re-initialize it when they change
+        options.track();
         if let Some(element) = element_ref() {
-            let jsselect = JsSelect::new(&element);
+            // `dispose()` removes this attribute, so it must be added again when re-initializing
+            element.set_attribute("data-te-select-init", "").unwrap();
+            let jsselect = JsSelect::new(
+                &element,
+                options_config.with_value(|config| serde_wasm_bindgen::to_value(config).unwrap()),
+            );
 
-            let on_change = on_change.clone();
-            let on_value_change = Closure::new(move |option_value: String| {
-                options.with_value(|options| {
-                    let mut options_iter = options
+            let on_value_change = Closure::new(move |option_values: Vec<String>| {
+                let selected = options.with_untracked(|options| {
+                    option_values
                         .iter()
-                        .filter(|option| option.value() == option_value);
-                    let Some(value) = options_iter.next() else {
-                        panic!("Select value changed to an invalid value: {}", option_value);
-                    };
-                    if options_iter.next().is_some() {
-                        panic!(
-                            "Select value changed to a duplicate value: {}",
-                            option_value
-                        );
-                    };
-                    on_change(value.clone());
-                })
+                        // The hidden option for "nothing selected" has an empty value
+                        .filter(|option_value| !option_value.is_empty())
+                        .filter_map(|option_value| {
+                            // Duplicate values were already reported when the options were set, so just take the first one here
+                            let option = options
+                                .iter()
+                                .find(|option| &option.value() == option_value);
+                            if option.is_none() {
+                                report_error(SelectError::UnknownValue(option_value.clone()));
+                            }
+                            option.cloned()
+                        })
+                        .collect()
+                });
+                on_change.with_value(|on_change| on_change(selected));
             });
 
-            let jsselect = Arc::new(jsselect);
-            let jsselect_clone = Arc::clone(&jsselect);
-            // TODO Is this effect cleaned up correctly? What if the outer effect is re-run and this gets re-created?
+            let jsselect = Rc::new(jsselect);
+            let jsselect_clone = Rc::clone(&jsselect);
             create_effect(move |_| {
-                jsselect_clone.setValue(value().value());
+                let mut selected_values = selected_values();
+                if selected_values.is_empty() && !multiple {
+                    selected_values.push("".to_string());
+                }
+                jsselect_clone.setValue(selected_values);
             });
 
-            // TODO leptos_use has an addEventListener function that automatically cleans itself up on scope exit. We probably should use that.
-            te_select_add_event_listener(&element, "valueChange.te.select", &on_value_change);
+            let listener =
+                te_select_add_event_listener(&element, "valueChange.te.select", &on_value_change);
 
             on_cleanup(move || {
+                te_select_remove_event_listener(&element, "valueChange.te.select", &listener);
                 jsselect.dispose();
+                // Tailwind Elements adds its own hidden option for the placeholder each time it is initialized
+                te_select_remove_placeholder_options(&element);
                 std::mem::drop(on_value_change);
             });
         }
     });
 
-    options.with_value(|options| {
-        view! {
-            <div>
-                // TODO Why is the `data-te-select-init` needed? Without it, layout of the label is broken.
-                <select data-te-select-init id=id.clone() ref=element_ref>
-                    {options.into_iter().map(|option| {
-                        view! {
-                            <option value=option.value()>{option.view()}</option>
-                        }
-                    }).collect::<Vec<_>>()}
-                </select>
-                <label for=id data-te-select-label-ref>{label}</label>
-            </div>
+    view! {
+        // The validation feedback is shown below the select input, so it needs more space then
+        <div class="relative" class=("mb-8", move || validity().is_some())>
+            // TODO Why is the `data-te-select-init` needed? Without it, layout of the label is broken.
+            <select
+                data-te-select-init
+                id=id.clone()
+                name=name
+                multiple=multiple
+                aria-invalid=move || (validity() == Some(false)).then_some("true")
+                ref=element_ref
+            >
+                {(!multiple).then(|| view! {
+                    // Selected when no option is selected, because otherwise the browser selects the first option
+                    <option
+                        value=""
+                        hidden
+                        data-leptos-select-none
+                        prop:selected=move || selected_values.with(Vec::is_empty)
+                    ></option>
+                })}
+                {move || options.with(|options| {
+                    group_options(options).into_iter().map(|entry| match entry {
+                        OptionEntry::Option(option) => option_view(option, selected_values),
+                        OptionEntry::Group(label, options) => view! {
+                            <optgroup label=label>
+                                {options.into_iter().map(|option| option_view(option, selected_values)).collect_view()}
+                            </optgroup>
+                        }.into_view(),
+                    }).collect_view()
+                })}
+            </select>
+            // Tailwind Elements adds its own classes to the label, so only toggle the validation classes
+            <label
+                for=id
+                class=("!text-[#14a44d]", move || validity() == Some(true))
+                class=("!text-[#dc4c64]", move || validity() == Some(false))
+                data-te-select-label-ref
+            >
+                {label}
+            </label>
+            <FieldFeedback state=field />
+        </div>
+    }
+}
+
+/// An error that happened while handling the value of a [Select] or [MultiSelect].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SelectError {
+    /// Multiple options have the same [SelectOption::value]. Only the first of them can be selected.
+    DuplicateValue(String),
+    /// Tailwind Elements reported a value that doesn't belong to any option. The value is ignored.
+    UnknownValue(String),
+}
+
+impl Display for SelectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateValue(value) => {
+                write!(f, "Select has multiple options with the value {value:?}")
+            }
+            Self::UnknownValue(value) => {
+                write!(f, "Select value changed to an unknown value {value:?}")
+            }
         }
-    })
+    }
+}
+
+impl std::error::Error for SelectError {}
+
+fn find_duplicate_value<O: SelectOption>(options: &[O]) -> Option<String> {
+    let mut seen = std::collections::HashSet::new();
+    options
+        .iter()
+        .map(SelectOption::value)
+        .find(|value| !seen.insert(value.clone()))
+}
+
+fn option_view<O: SelectOption>(option: &O, selected_values: Memo<Vec<String>>) -> View {
+    let option_value = option.value();
+    let is_selected = {
+        let option_value = option_value.clone();
+        move || selected_values.with(|selected| selected.contains(&option_value))
+    };
+    view! {
+        <option
+            value=option_value
+            disabled=option.disabled()
+            data-te-select-secondary-text=option.secondary_text()
+            data-te-select-icon=option.icon()
+            prop:selected=is_selected
+        >
+            {option.view()}
+        </option>
+    }
+    .into_view()
+}
+
+enum OptionEntry<'a, O> {
+    Option(&'a O),
+    Group(String, Vec<&'a O>),
+}
+
+fn group_options<O: SelectOption>(options: &[O]) -> Vec<OptionEntry<'_, O>> {
+    let mut entries: Vec<OptionEntry<'_, O>> = Vec::new();
+    for option in options {
+        let Some(group) = option.group() else {
+            entries.push(OptionEntry::Option(option));
+            continue;
+        };
+        let existing_group = entries.iter_mut().find_map(|entry| match entry {
+            OptionEntry::Group(label, options) if *label == group => Some(options),
+            _ => None,
+        });
+        match existing_group {
+            Some(options) => options.push(option),
+            None => entries.push(OptionEntry::Group(group, vec![option])),
+        }
+    }
+    entries
 }
 
 #[wasm_bindgen]
@@ -101,27 +482,59 @@ extern "C" {
     #[wasm_bindgen(js_namespace = te, js_name = Select)]
     type JsSelect;
 
-    // TODO Select constructor can take some options, see https://tailwind-elements.com/docs/standard/forms/select/#docsTabsAPI
     #[wasm_bindgen(constructor, js_namespace = te, js_class = Select, final)]
-    fn new(e: &HtmlSelectElement) -> JsSelect;
+    fn new(e: &HtmlSelectElement, options: JsValue) -> JsSelect;
 
     #[wasm_bindgen(method, js_namespace = te, js_class = Select, final)]
-    fn setValue(this: &JsSelect, value: String);
+    fn setValue(this: &JsSelect, values: Vec<String>);
 
     #[wasm_bindgen(method, js_namespace = te, js_class = Select, final)]
     fn dispose(this: &JsSelect);
 }
 
-#[wasm_bindgen(
-    inline_js = "export function te_select_add_event_listener(select_html_event, event_name, callback) { select_html_event.addEventListener(event_name, (event) => {
-        callback(event.target.value);
-    }); }"
-)]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsSelectOptions {
+    multiple: bool,
+    select_filter: bool,
+    select_clear_button: bool,
+    select_placeholder: String,
+    select_all: bool,
+    select_all_label: String,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/forms/select/#docsTabsAPI
+}
+
+#[wasm_bindgen(inline_js = "
+    export function te_select_add_event_listener(select_html_elem, event_name, callback) {
+        const listener = (event) => {
+            // `event.value` is an array for multi selects and null (or undefined) if the selection was cleared
+            callback(event.value == null ? [] : [].concat(event.value));
+        };
+        select_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_select_remove_event_listener(select_html_elem, event_name, listener) {
+        select_html_elem.removeEventListener(event_name, listener);
+    }
+    export function te_select_remove_placeholder_options(select_html_elem) {
+        select_html_elem.querySelectorAll(':scope > option[hidden]:not([data-leptos-select-none])').forEach((option) => option.remove());
+    }
+")]
 extern "C" {
     #[wasm_bindgen]
     fn te_select_add_event_listener(
-        carousel: &web_sys::HtmlElement,
+        select: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut(Vec<String>)>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_select_remove_event_listener(
+        select: &web_sys::HtmlElement,
         event_name: &str,
-        callback: &Closure<dyn FnMut(String)>,
+        listener: &JsValue,
     );
+
+    #[wasm_bindgen]
+    fn te_select_remove_placeholder_options(select: &web_sys::HtmlElement);
 }
@@ -1,5 +1,7 @@
 use leptos::*;
 
+use super::validation::{FieldFeedback, FieldState, Rule};
+
 /// A Toggle Switch component
 ///
 /// See [Tailwind Elements: Switch](https://tailwind-elements.com/docs/standard/forms/switch/)
@@ -19,6 +21,13 @@ pub fn Switch<OnChangeFn: Fn(bool) + 'static>(
     /// The id of the switch. Useful to associate a label with it.
     #[prop(into, default = "".into())]
     id: String,
+    /// The name of the switch. Inside of a [Form](super::Form), the switch is validated if it has a name.
+    /// Its value is `"true"` or `"false"`.
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// The rules the switch is validated with, if it is inside of a [Form](super::Form), usually [Rule::checked].
+    #[prop(optional)]
+    rules: Vec<Rule>,
 ) -> impl IntoView {
     let class = move || {
         let mut class = "mr-2 mt-[0.3rem] h-3.5 w-8 appearance-none rounded-[0.4375rem] bg-neutral-300 before:pointer-events-none before:absolute before:h-3.5 before:w-3.5 before:rounded-full before:bg-transparent before:content-[''] after:absolute after:z-[2] after:-mt-[0.1875rem] after:h-5 after:w-5 after:rounded-full after:border-none after:bg-neutral-100 after:shadow-[0_0px_3px_0_rgb(0_0_0_/_7%),_0_2px_2px_0_rgb(0_0_0_/_4%)] after:transition-[background-color_0.2s,transform_0.2s] after:content-[''] checked:bg-primary checked:after:absolute checked:after:z-[2] checked:after:-mt-[3px] checked:after:ml-[1.0625rem] checked:after:h-5 checked:after:w-5 checked:after:rounded-full checked:after:border-none checked:after:bg-primary checked:after:shadow-[0_3px_1px_-2px_rgba(0,0,0,0.2),_0_2px_2px_0_rgba(0,0,0,0.14),_0_1px_5px_0_rgba(0,0,0,0.12)] checked:after:transition-[background-color_0.2s,transform_0.2s] checked:after:content-[''] hover:cursor-pointer focus:outline-none focus:ring-0 focus:before:scale-100 focus:before:opacity-[0.12] focus:before:shadow-[3px_-1px_0px_13px_rgba(0,0,0,0.6)] focus:before:transition-[box-shadow_0.2s,transform_0.2s] focus:after:absolute focus:after:z-[1] focus:after:block focus:after:h-5 focus:after:w-5 focus:after:rounded-full focus:after:content-[''] checked:focus:border-primary checked:focus:bg-primary checked:focus:before:ml-[1.0625rem] checked:focus:before:scale-100 checked:focus:before:shadow-[3px_-1px_0px_13px_#3b71ca] checked:focus:before:transition-[box-shadow_0.2s,transform_0.2s] dark:bg-neutral-600 dark:after:bg-neutral-400 dark:checked:bg-primary dark:checked:after:bg-primary dark:focus:before:shadow-[3px_-1px_0px_13px_rgba(255,255,255,0.4)] dark:checked:focus:before:shadow-[3px_-1px_0px_13px_#3b71ca]".to_string();
@@ -28,17 +37,38 @@ pub fn Switch<OnChangeFn: Fn(bool) + 'static>(
         class
     };
 
-    view! {
+    let field = FieldState::register(
+        name.clone(),
+        Signal::derive(move || checked.get()),
+        |checked: &bool| vec![checked.to_string()],
+        rules,
+    );
+    let validity = FieldState::validity(field);
+
+    let input = view! {
         <input
             type="checkbox"
             role="switch"
             class=class
             id=if id.is_empty() { None } else { Some(id) }
+            name=name
+            aria-invalid=move || (validity() == Some(false)).then_some("true")
             disabled=disabled
             prop:checked=checked
             on:input=move |ev| {
                 on_change(event_target_checked(&ev));
             }
         />
+    };
+    match field {
+        None => input.into_view(),
+        // The validation feedback is shown below the switch
+        Some(_) => view! {
+            <span class="relative inline-block" class=("mb-8", move || validity().is_some())>
+                {input}
+                <FieldFeedback state=field />
+            </span>
+        }
+        .into_view(),
     }
 }
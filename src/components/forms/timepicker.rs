@@ -0,0 +1,357 @@
+use leptos::{html::Div, *};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::{wasm_bindgen, JsValue};
+use web_sys::HtmlElement;
+
+use super::input::JsInput;
+
+/// A time type that can be used with a [Timepicker].
+///
+/// This is implemented for `chrono::NaiveTime` if the `chrono` feature is enabled
+/// and for `time::Time` if the `time` feature is enabled.
+pub trait TimepickerTime: Copy + 'static {
+    /// Create a time from hour (0-23) and minute (0-59). Returns `None` if the time doesn't exist.
+    fn from_hm(hour: u32, minute: u32) -> Option<Self>;
+
+    /// Returns hour (0-23) and minute (0-59) of the time.
+    fn to_hm(&self) -> (u32, u32);
+}
+
+#[cfg(feature = "chrono")]
+impl TimepickerTime for chrono::NaiveTime {
+    fn from_hm(hour: u32, minute: u32) -> Option<Self> {
+        chrono::NaiveTime::from_hms_opt(hour, minute, 0)
+    }
+
+    fn to_hm(&self) -> (u32, u32) {
+        use chrono::Timelike;
+        (self.hour(), self.minute())
+    }
+}
+
+#[cfg(feature = "time")]
+impl TimepickerTime for time::Time {
+    fn from_hm(hour: u32, minute: u32) -> Option<Self> {
+        time::Time::from_hms(u8::try_from(hour).ok()?, u8::try_from(minute).ok()?, 0).ok()
+    }
+
+    fn to_hm(&self) -> (u32, u32) {
+        (u32::from(self.hour()), u32::from(self.minute()))
+    }
+}
+
+/// Whether a [Timepicker] uses a 12 hour clock with AM/PM or a 24 hour clock.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimepickerFormat {
+    /// 12 hour clock, e.g. `09:30 PM`
+    #[default]
+    Hours12,
+    /// 24 hour clock, e.g. `21:30`
+    Hours24,
+}
+
+/// A Timepicker component
+///
+/// The time type is generic, see [TimepickerTime]. Enable the `chrono` or `time` feature to use it with those crates.
+///
+/// See [Tailwind Elements: Timepicker](https://tailwind-elements.com/docs/standard/forms/timepicker/)
+#[component]
+pub fn Timepicker<T: TimepickerTime, OnChangeFn: Fn(Option<T>) + 'static>(
+    /// This signal is read to show the currently selected time.
+    /// Whenever this signal changes, the timepicker is updated.
+    /// But note that the time can also be changed by the user and if the `on_change` function doesn't
+    /// update the `value` signal, the timepicker can get out of sync with the signal.
+    #[prop(into)]
+    value: MaybeSignal<Option<T>>,
+    /// Callback that is called when the user selects a time, or with `None` when the user clears the time.
+    on_change: OnChangeFn,
+    /// Whether to use a 12 hour or a 24 hour clock. See [TimepickerFormat].
+    #[prop(into, default = TimepickerFormat::Hours12.into())]
+    format: MaybeSignal<TimepickerFormat>,
+    /// Times before this time can't be selected.
+    #[prop(into, default = None.into())]
+    min: MaybeSignal<Option<T>>,
+    /// Times after this time can't be selected.
+    #[prop(into, default = None.into())]
+    max: MaybeSignal<Option<T>>,
+    /// If `true`, the minutes can only be selected in steps of 5 minutes.
+    #[prop(into, default = false.into())]
+    increment: MaybeSignal<bool>,
+    /// If `true`, the clock is shown in a dropdown below the input field. If `false`, it is shown in a modal.
+    #[prop(into, default = false.into())]
+    inline: MaybeSignal<bool>,
+    /// Whether the timepicker is disabled.
+    #[prop(into, default = false.into())]
+    disabled: MaybeSignal<bool>,
+    /// The id of the input field. Useful to associate a label with it.
+    #[prop(into, default = "".into())]
+    id: String,
+    /// This label is shown as a placeholder while the field is empty and it is shown as a label at the top of the input field when it is non-empty or has focus.
+    #[prop(into, default = "".into())]
+    label: MaybeSignal<String>,
+) -> impl IntoView {
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let format = format();
+            let options = JsTimepickerOptions {
+                format12: format == TimepickerFormat::Hours12,
+                format24: format == TimepickerFormat::Hours24,
+                min_time: min()
+                    .map(|time| format_time(format, &time))
+                    .unwrap_or_default(),
+                max_time: max()
+                    .map(|time| format_time(format, &time))
+                    .unwrap_or_default(),
+                increment: increment(),
+                inline: inline(),
+            };
+            let jsinput = JsInput::new(&element);
+            let jstimepicker =
+                JsTimepicker::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
+            on_cleanup(move || {
+                jstimepicker.dispose();
+                jsinput.dispose();
+            });
+        }
+    });
+
+    let id = if id.is_empty() { None } else { Some(id) };
+    let text = move || {
+        value()
+            .map(|time| format_time(format(), &time))
+            .unwrap_or_default()
+    };
+    let on_input = move |text: String| {
+        if text.trim().is_empty() {
+            on_change(None);
+        } else if let Some(time) = parse_time(&text)
+            // Tailwind Elements doesn't check typed times against `min` and `max`, so we do it here.
+            .filter(|time| is_time_in_range(time, min.get_untracked(), max.get_untracked()))
+        {
+            on_change(Some(time));
+        }
+        // Otherwise, the user is still typing and the input isn't a valid time yet, or the time can't be selected.
+    };
+
+    view! {
+        <div ref=element_ref class="relative mb-3">
+            <input
+                type="text"
+                class="peer block min-h-[auto] w-full rounded border-0 bg-transparent px-3 py-[0.32rem] leading-[1.6] outline-none transition-all duration-200 ease-linear focus:placeholder:opacity-100 peer-focus:text-primary data-[te-input-state-active]:placeholder:opacity-100 motion-reduce:transition-none dark:text-neutral-200 dark:placeholder:text-neutral-200 dark:peer-focus:text-primary [&:not([data-te-input-placeholder-active])]:placeholder:opacity-0"
+                placeholder=label.clone()
+                aria-label=label.clone()
+                id=id.clone()
+                disabled=disabled
+                prop:value=text
+                on:input=move |ev| on_input(event_target_value(&ev))
+            />
+            <label
+                for=id
+                class="pointer-events-none absolute left-3 top-0 mb-0 max-w-[90%] origin-[0_0] truncate pt-[0.37rem] leading-[1.6] text-neutral-500 transition-all duration-200 ease-out peer-focus:-translate-y-[0.9rem] peer-focus:scale-[0.8] peer-focus:text-primary peer-data-[te-input-state-active]:-translate-y-[0.9rem] peer-data-[te-input-state-active]:scale-[0.8] motion-reduce:transition-none dark:text-neutral-200 dark:peer-focus:text-primary"
+            >
+                {label}
+            </label>
+        </div>
+    }
+}
+
+/// Formats a time the way Tailwind Elements shows it in the input field.
+pub(super) fn format_time<T: TimepickerTime>(format: TimepickerFormat, time: &T) -> String {
+    let (hour, minute) = time.to_hm();
+    match format {
+        TimepickerFormat::Hours12 => {
+            let am_pm = if hour < 12 { "AM" } else { "PM" };
+            let hour = match hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            format!("{hour:02}:{minute:02} {am_pm}")
+        }
+        TimepickerFormat::Hours24 => format!("{hour:02}:{minute:02}"),
+    }
+}
+
+/// Parses a time in either 12 hour or 24 hour format, e.g. `9:30 PM` or `21:30`.
+pub(super) fn parse_time<T: TimepickerTime>(input: &str) -> Option<T> {
+    let input = input.trim();
+    let (time, am_pm) = match input.rsplit_once(' ') {
+        Some((time, am_pm)) => (time.trim(), Some(am_pm.to_ascii_uppercase())),
+        None => (input, None),
+    };
+    let (hour, minute) = time.split_once(':')?;
+    let hour: u32 = hour.parse().ok()?;
+    let minute: u32 = minute.parse().ok()?;
+    let hour = match am_pm.as_deref() {
+        None => hour,
+        Some("AM") if (1..=12).contains(&hour) => hour % 12,
+        Some("PM") if (1..=12).contains(&hour) => hour % 12 + 12,
+        Some(_) => return None,
+    };
+    T::from_hm(hour, minute)
+}
+
+// Whether the time is between `min` and `max`, inclusive.
+pub(super) fn is_time_in_range<T: TimepickerTime>(
+    time: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> bool {
+    let time = time.to_hm();
+    min.map_or(true, |min| min.to_hm() <= time) && max.map_or(true, |max| time <= max.to_hm())
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Timepicker)]
+    type JsTimepicker;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Timepicker, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsTimepicker;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Timepicker, final)]
+    fn dispose(this: &JsTimepicker);
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JsTimepickerOptions {
+    pub(super) format12: bool,
+
+    pub(super) format24: bool,
+
+    pub(super) min_time: String,
+
+    pub(super) max_time: String,
+
+    pub(super) increment: bool,
+
+    pub(super) inline: bool,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/forms/timepicker/#docsTabsAPI
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    mod generic {
+        use super::super::*;
+        use std::fmt::Debug;
+
+        fn time<T: TimepickerTime>(hour: u32, minute: u32) -> Option<T> {
+            T::from_hm(hour, minute)
+        }
+
+        pub fn round_trip<T: TimepickerTime + Debug + PartialEq>() {
+            for (hour, minute) in [
+                (0, 0),
+                (0, 30),
+                (9, 5),
+                (11, 59),
+                (12, 0),
+                (12, 30),
+                (23, 59),
+            ] {
+                let value = time::<T>(hour, minute).unwrap();
+                for format in [TimepickerFormat::Hours12, TimepickerFormat::Hours24] {
+                    let text = format_time(format, &value);
+                    assert_eq!(Some(value), parse_time::<T>(&text), "{format:?}: {text:?}");
+                }
+            }
+        }
+
+        pub fn format<T: TimepickerTime>() {
+            let value = time::<T>(21, 5).unwrap();
+            assert_eq!("09:05 PM", format_time(TimepickerFormat::Hours12, &value));
+            assert_eq!("21:05", format_time(TimepickerFormat::Hours24, &value));
+            let midnight = time::<T>(0, 0).unwrap();
+            assert_eq!(
+                "12:00 AM",
+                format_time(TimepickerFormat::Hours12, &midnight)
+            );
+            assert_eq!("00:00", format_time(TimepickerFormat::Hours24, &midnight));
+        }
+
+        pub fn parse<T: TimepickerTime + Debug + PartialEq>() {
+            assert_eq!(time::<T>(0, 0), parse_time::<T>("12:00 AM"));
+            assert_eq!(time::<T>(12, 30), parse_time::<T>("12:30 PM"));
+            assert_eq!(time::<T>(9, 30), parse_time::<T>("9:30 AM"));
+            assert_eq!(time::<T>(21, 30), parse_time::<T>("9:30 pm"));
+            assert_eq!(time::<T>(21, 30), parse_time::<T>(" 09:30 PM "));
+            assert_eq!(time::<T>(21, 30), parse_time::<T>("21:30"));
+            assert_eq!(time::<T>(0, 5), parse_time::<T>("0:05"));
+            assert_eq!(None, parse_time::<T>("0:30 AM"));
+            assert_eq!(None, parse_time::<T>("13:00 PM"));
+            assert_eq!(None, parse_time::<T>("9:30 XM"));
+            assert_eq!(None, parse_time::<T>("24:00"));
+            assert_eq!(None, parse_time::<T>("9:60"));
+            assert_eq!(None, parse_time::<T>("9"));
+            assert_eq!(None, parse_time::<T>(""));
+        }
+
+        pub fn in_range<T: TimepickerTime>() {
+            let time = |hour, minute| T::from_hm(hour, minute).unwrap();
+            let min = Some(time(9, 0));
+            let max = Some(time(17, 30));
+            assert!(is_time_in_range(&time(12, 0), min, max));
+            assert!(is_time_in_range(&time(9, 0), min, max));
+            assert!(is_time_in_range(&time(17, 30), min, max));
+            assert!(!is_time_in_range(&time(8, 59), min, max));
+            assert!(!is_time_in_range(&time(17, 31), min, max));
+            assert!(!is_time_in_range(&time(0, 0), min, max));
+            assert!(is_time_in_range(&time(0, 0), None, max));
+            assert!(is_time_in_range(&time(23, 59), min, None));
+            assert!(is_time_in_range(&time(23, 59), None, None));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono {
+        use super::generic;
+
+        #[test]
+        fn round_trip() {
+            generic::round_trip::<::chrono::NaiveTime>();
+        }
+
+        #[test]
+        fn format() {
+            generic::format::<::chrono::NaiveTime>();
+        }
+
+        #[test]
+        fn parse() {
+            generic::parse::<::chrono::NaiveTime>();
+        }
+
+        #[test]
+        fn in_range() {
+            generic::in_range::<::chrono::NaiveTime>();
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time {
+        use super::generic;
+
+        #[test]
+        fn round_trip() {
+            generic::round_trip::<::time::Time>();
+        }
+
+        #[test]
+        fn format() {
+            generic::format::<::time::Time>();
+        }
+
+        #[test]
+        fn parse() {
+            generic::parse::<::time::Time>();
+        }
+
+        #[test]
+        fn in_range() {
+            generic::in_range::<::time::Time>();
+        }
+    }
+}
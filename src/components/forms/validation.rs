@@ -0,0 +1,608 @@
+use leptos::*;
+#[cfg(feature = "regex")]
+use regex::Regex;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::rc::Rc;
+use std::str::FromStr;
+
+// The validation is implemented in Rust instead of using the `te.Validation` JavaScript class,
+// because `te.Validation::dispose()` resets the `innerHTML` of the fields, which would break the DOM nodes managed by leptos.
+// The styles and messages are the ones from Tailwind Elements.
+const VALID_FEEDBACK_CLASS: &str =
+    "absolute top-full left-0 m-1 w-auto text-sm text-[#14a44d] animate-[fade-in_0.3s_both]";
+const INVALID_FEEDBACK_CLASS: &str =
+    "absolute top-full left-0 m-1 w-auto text-sm text-[#dc4c64] animate-[fade-in_0.3s_both]";
+
+/// A validation rule for a field of a [Form].
+///
+/// Rules check the value of the field as a string. For [Select](super::Select) fields, this is the [SelectOption::value](super::SelectOption::value)
+/// of the selected option, for [MultiSelect](super::MultiSelect) fields the values of the selected options separated by commas,
+/// and for [Switch](super::Switch) fields `"true"` or `"false"`.
+///
+/// See [Tailwind Elements: Validation](https://tailwind-elements.com/docs/standard/forms/validation/)
+#[derive(Clone)]
+pub struct Rule {
+    check: Rc<RuleFn>,
+}
+
+type RuleFn = dyn Fn(&str) -> Result<(), String>;
+
+impl Rule {
+    /// The field must not be empty or only consist of whitespace.
+    pub fn required() -> Self {
+        Self::custom(|value| {
+            if value.trim().is_empty() {
+                Err("This is required".to_string())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// The field must contain an email address. Empty fields are accepted, combine this with [Rule::required] if necessary.
+    pub fn email() -> Self {
+        Self::custom(|value| {
+            if value.is_empty() || is_email(value) {
+                Ok(())
+            } else {
+                Err("Please enter a valid email address".to_string())
+            }
+        })
+    }
+
+    /// The field must contain at least `length` characters.
+    pub fn min_length(length: usize) -> Self {
+        Self::custom(move |value| {
+            if value.chars().count() >= length {
+                Ok(())
+            } else {
+                Err(format!("This field must have at least {length} characters"))
+            }
+        })
+    }
+
+    /// The field must contain at most `length` characters.
+    pub fn max_length(length: usize) -> Self {
+        Self::custom(move |value| {
+            if value.chars().count() <= length {
+                Ok(())
+            } else {
+                Err(format!("This field must have at most {length} characters"))
+            }
+        })
+    }
+
+    /// The field must match the regular expression `pattern`, otherwise `message` is shown.
+    /// Use `^` and `$` in the pattern to match the whole field. Returns an error if `pattern` isn't a valid regular expression.
+    ///
+    /// This is only available with the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str, message: impl Into<String>) -> Result<Self, regex::Error> {
+        let pattern = Regex::new(pattern)?;
+        let message = message.into();
+        Ok(Self::custom(move |value| {
+            if pattern.is_match(value) {
+                Ok(())
+            } else {
+                Err(message.clone())
+            }
+        }))
+    }
+
+    /// The [Switch](super::Switch) must be switched on.
+    pub fn checked() -> Self {
+        Self::custom(|value| {
+            if value == "true" {
+                Ok(())
+            } else {
+                Err("This is required".to_string())
+            }
+        })
+    }
+
+    /// A custom rule. `check` returns `Err` with the message to show if the value is invalid.
+    pub fn custom(check: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        Self {
+            check: Rc::new(check),
+        }
+    }
+
+    fn check(&self, value: &str) -> Result<(), String> {
+        (self.check)(value)
+    }
+}
+
+// The same check as the pattern `^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$`
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    let Some((host, tld)) = domain.rsplit_once('.') else {
+        return false;
+    };
+    !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c))
+        && !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".-".contains(c))
+        && tld.len() >= 2
+        && tld.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+struct Field {
+    id: usize,
+    name: String,
+    texts: Memo<Vec<String>>,
+    typed_value: Rc<dyn Fn() -> Rc<dyn Any>>,
+    error: Memo<Option<String>>,
+}
+
+/// The state of a [Form]. Create it with [use_form] to access the form from outside, e.g. to validate it manually.
+///
+/// Fields inside the form register themselves with it when they have a `name`.
+#[derive(Clone, Copy)]
+pub struct FormContext {
+    fields: StoredValue<Vec<Field>>,
+    next_field_id: StoredValue<usize>,
+    validated: RwSignal<bool>,
+    valid_feedback: StoredValue<String>,
+}
+
+impl FormContext {
+    fn new() -> Self {
+        Self {
+            fields: store_value(Vec::new()),
+            next_field_id: store_value(0),
+            validated: create_rw_signal(false),
+            valid_feedback: store_value("Looks good!".to_string()),
+        }
+    }
+
+    /// Validate all fields and show their feedback. Afterwards, the feedback is updated whenever a field changes.
+    /// Returns whether all fields are valid.
+    pub fn validate(&self) -> bool {
+        self.validated.set(true);
+        self.fields.with_value(|fields| {
+            fields
+                .iter()
+                .all(|field| field.error.with_untracked(Option::is_none))
+        })
+    }
+
+    /// Hide the feedback of all fields until the form is validated again.
+    pub fn reset_validation(&self) {
+        self.validated.set(false);
+    }
+
+    /// Whether the form was validated and the feedback is shown.
+    pub fn validated(&self) -> Signal<bool> {
+        self.validated.into()
+    }
+
+    /// Whether all fields are currently valid. This is independent of whether the feedback is shown.
+    pub fn is_valid(&self) -> Signal<bool> {
+        let fields = self.fields;
+        Signal::derive(move || {
+            fields.with_value(|fields| fields.iter().all(|field| field.error.with(Option::is_none)))
+        })
+    }
+
+    /// The current values of all fields.
+    pub fn values(&self) -> FormValues {
+        FormValues {
+            values: self.fields.with_value(|fields| {
+                fields
+                    .iter()
+                    .map(|field| {
+                        let value = FieldValue {
+                            texts: field.texts.get_untracked(),
+                            typed: (field.typed_value)(),
+                        };
+                        (field.name.clone(), value)
+                    })
+                    .collect()
+            }),
+        }
+    }
+
+    // Registers a field and unregisters it again when the field is removed from the page.
+    // `texts` converts the value into the strings the rules check and [FormValues::get_all] returns.
+    pub(super) fn register<T: Clone + 'static>(
+        &self,
+        name: String,
+        value: Signal<T>,
+        texts: impl Fn(&T) -> Vec<String> + 'static,
+        rules: Vec<Rule>,
+    ) -> FieldState {
+        let texts = create_memo(move |_| value.with(&texts));
+        let error = create_memo(move |_| {
+            let value = texts.with(|texts| texts.join(","));
+            rules.iter().find_map(|rule| rule.check(&value).err())
+        });
+        let typed_value = Rc::new(move || Rc::new(value.get_untracked()) as Rc<dyn Any>);
+        let id = self.next_field_id.get_value();
+        self.next_field_id.set_value(id + 1);
+        self.fields.update_value(|fields| {
+            fields.push(Field {
+                id,
+                name,
+                texts,
+                typed_value,
+                error,
+            })
+        });
+        let fields = self.fields;
+        on_cleanup(move || {
+            fields.update_value(|fields| fields.retain(|field| field.id != id));
+        });
+
+        let validated = self.validated;
+        let valid_feedback = self.valid_feedback;
+        FieldState {
+            state: Signal::derive(move || {
+                if !validated() {
+                    None
+                } else {
+                    Some(error().ok_or_else(|| valid_feedback.get_value()))
+                }
+            }),
+        }
+    }
+}
+
+/// Create the state of a [Form] so it can be accessed from outside of the form. Pass it to the form with `<Form form=form ...>`.
+///
+/// Example
+/// -------
+/// ```ignore
+/// let form = use_form();
+/// view! {
+///     <Form form=form on_submit=move |values| save(values)>
+///         ...
+///     </Form>
+///     <Button on_click=move |_| { form.validate(); }>"Check"</Button>
+/// }
+/// ```
+pub fn use_form() -> FormContext {
+    FormContext::new()
+}
+
+/// The values of the fields of a [Form], by their `name`.
+///
+/// The values can either be read as text with [FormValues::get] and [FormValues::get_all],
+/// or with the type the field works with using [FormValues::get_typed].
+#[derive(Clone, Default)]
+pub struct FormValues {
+    values: HashMap<String, FieldValue>,
+}
+
+#[derive(Clone)]
+struct FieldValue {
+    texts: Vec<String>,
+    typed: Rc<dyn Any>,
+}
+
+impl FormValues {
+    /// The value of the field with the given name as text, or `None` if there is no such field.
+    /// For a [MultiSelect](super::MultiSelect), this is the first selected value, see [FormValues::get_all].
+    /// If nothing is selected in a [Select](super::Select) or [MultiSelect](super::MultiSelect), this is an empty string.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let texts = self.get_all(name)?;
+        Some(texts.first().map_or("", String::as_str))
+    }
+
+    /// All values of the field with the given name as text, or `None` if there is no such field.
+    /// For a [MultiSelect](super::MultiSelect), these are the [SelectOption::value](super::SelectOption::value)s
+    /// of all selected options. Other fields have at most one value.
+    pub fn get_all(&self, name: &str) -> Option<&[String]> {
+        self.values.get(name).map(|value| value.texts.as_slice())
+    }
+
+    /// The value of the field with the given name, parsed into `T`.
+    /// Returns `None` if there is no such field or its value can't be parsed.
+    pub fn parse<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.get(name)?.parse().ok()
+    }
+
+    /// The value of the field with the given name as the type the field works with, i.e. `String` for an [Input](super::Input),
+    /// `bool` for a [Switch](super::Switch), `Option<O>` for a [`Select<O>`](super::Select) and `Vec<O>` for a [`MultiSelect<O>`](super::MultiSelect).
+    /// Returns `None` if there is no such field or its value has a different type.
+    pub fn get_typed<T: Clone + 'static>(&self, name: &str) -> Option<T> {
+        self.values.get(name)?.typed.downcast_ref::<T>().cloned()
+    }
+}
+
+impl Debug for FormValues {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.values.iter().map(|(name, value)| (name, &value.texts)))
+            .finish()
+    }
+}
+
+// The feedback a field shows. `None` while the form isn't validated,
+// otherwise `Ok` with the valid feedback or `Err` with the error message.
+#[derive(Clone, Copy)]
+pub(super) struct FieldState {
+    state: Signal<Option<Result<String, String>>>,
+}
+
+impl FieldState {
+    // Registers the field with the surrounding form, if there is one and the field has a name.
+    pub(super) fn register<T: Clone + 'static>(
+        name: Option<String>,
+        value: Signal<T>,
+        texts: impl Fn(&T) -> Vec<String> + 'static,
+        rules: Vec<Rule>,
+    ) -> Option<Self> {
+        let form = use_context::<FormContext>()?;
+        Some(form.register(name?, value, texts, rules))
+    }
+
+    // Whether the field is valid, or `None` while the form isn't validated or the field isn't part of a form
+    pub(super) fn validity(field: Option<Self>) -> Signal<Option<bool>> {
+        Signal::derive(move || {
+            field.and_then(|field| field.state.with(|state| state.as_ref().map(Result::is_ok)))
+        })
+    }
+}
+
+#[component]
+pub(super) fn FieldFeedback(state: Option<FieldState>) -> impl IntoView {
+    move || {
+        let state = state?.state.get()?;
+        Some(match state {
+            Ok(valid_feedback) => (!valid_feedback.is_empty()).then(|| {
+                view! { <div class=VALID_FEEDBACK_CLASS>{valid_feedback}</div> }
+            }),
+            Err(invalid_feedback) => Some(view! {
+                <div class=INVALID_FEEDBACK_CLASS role="alert">{invalid_feedback}</div>
+            }),
+        })
+    }
+}
+
+/// A Form component. It validates the fields inside of it when it is submitted and only calls `on_submit` if all of them are valid.
+///
+/// Fields like [Input](super::Input), [Select](super::Select) and [Switch](super::Switch) take part in the validation
+/// if they have a `name`. Their `rules` are checked and a feedback text is shown below them.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Form on_submit=move |values: FormValues| {
+///         let email: Option<String> = values.get_typed("email");
+///         let age: Option<u32> = values.parse("age");
+///     }>
+///         <Input
+///             id="email" name="email" label="Email"
+///             value=email on_change=set_email
+///             rules=vec![Rule::required(), Rule::email()]
+///         />
+///         <Input
+///             id="age" name="age" label="Age" input_type=InputType::Number
+///             value=age on_change=set_age
+///             rules=vec![Rule::custom(|age| age.parse::<u32>().map(|_| ()).map_err(|_| "Please enter your age".to_string()))]
+///         />
+///         <Button style=ButtonStyle::Primary submit=true>"Submit"</Button>
+///     </Form>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Validation](https://tailwind-elements.com/docs/standard/forms/validation/)
+#[component]
+pub fn Form<OnSubmitFn: Fn(FormValues) + 'static>(
+    /// The state of the form, if it should be accessible from outside. See [use_form].
+    #[prop(optional)]
+    form: Option<FormContext>,
+    /// Callback that is called with the values of all fields when the form is submitted and all fields are valid.
+    on_submit: OnSubmitFn,
+    /// The text shown below valid fields. If empty, nothing is shown.
+    #[prop(into, default = "Looks good!".into())]
+    valid_feedback: String,
+    /// The fields and buttons of the form.
+    children: Children,
+) -> impl IntoView {
+    let form = form.unwrap_or_else(FormContext::new);
+    form.valid_feedback.set_value(valid_feedback);
+    provide_context(form);
+
+    view! {
+        <form
+            novalidate
+            data-te-validated=move || form.validated.get().then_some("true")
+            on:submit=move |ev| {
+                ev.prevent_default();
+                if form.validate() {
+                    on_submit(form.values());
+                }
+            }
+        >
+            {children()}
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accepts(rule: &Rule, value: &str) -> bool {
+        rule.check(value).is_ok()
+    }
+
+    #[test]
+    fn required() {
+        let rule = Rule::required();
+        assert!(accepts(&rule, "a"));
+        assert!(accepts(&rule, " a "));
+        assert!(!accepts(&rule, ""));
+        assert!(!accepts(&rule, " \t\n"));
+    }
+
+    #[test]
+    fn email() {
+        let rule = Rule::email();
+        assert!(accepts(&rule, ""));
+        assert!(accepts(&rule, "user@example.com"));
+        assert!(accepts(&rule, "first.last+tag@mail.example.co.uk"));
+        assert!(accepts(&rule, "a_b%c-d@sub-domain.example.org"));
+        assert!(accepts(&rule, "USER@EXAMPLE.COM"));
+        assert!(!accepts(&rule, "user"));
+        assert!(!accepts(&rule, "user@"));
+        assert!(!accepts(&rule, "@example.com"));
+        assert!(!accepts(&rule, "user@example"));
+        assert!(!accepts(&rule, "user@.com"));
+        assert!(!accepts(&rule, "user@example.c"));
+        assert!(!accepts(&rule, "user@example.c0m"));
+        assert!(!accepts(&rule, "user@@example.com"));
+        assert!(!accepts(&rule, "us er@example.com"));
+        assert!(!accepts(&rule, "user@exa_mple.com"));
+        assert!(!accepts(&rule, "üser@example.com"));
+        assert!(!accepts(&rule, " user@example.com"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn email_matches_pattern() {
+        let pattern = Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap();
+        for value in [
+            "user@example.com",
+            "a.b@c.de",
+            "a@b.c.de",
+            "a@-.de",
+            "a@..de",
+            "a@b.de.",
+            "a@b.d",
+            "a@b.",
+            "a@.de",
+            "a@b@c.de",
+            "a@bde",
+            "%+-@1-2.xyz",
+            "a@b.d3",
+            "ä@b.de",
+            "a@b.dé",
+            "a b@c.de",
+        ] {
+            assert_eq!(pattern.is_match(value), is_email(value), "{value}");
+        }
+    }
+
+    #[test]
+    fn min_length() {
+        let rule = Rule::min_length(3);
+        assert!(accepts(&rule, "abc"));
+        assert!(accepts(&rule, "abcd"));
+        assert!(!accepts(&rule, "ab"));
+        assert!(!accepts(&rule, ""));
+        // Characters are counted, not bytes
+        assert!(accepts(&rule, "äöü"));
+        assert!(!accepts(&rule, "äö"));
+        assert!(!accepts(&rule, "🦀"));
+    }
+
+    #[test]
+    fn max_length() {
+        let rule = Rule::max_length(3);
+        assert!(accepts(&rule, ""));
+        assert!(accepts(&rule, "abc"));
+        assert!(!accepts(&rule, "abcd"));
+        // Characters are counted, not bytes
+        assert!(accepts(&rule, "äöü"));
+        assert!(accepts(&rule, "🦀🦀🦀"));
+        assert!(!accepts(&rule, "äöüß"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex() {
+        let rule = Rule::regex(r"^\d{5}$", "Please enter a zip code").unwrap();
+        assert!(accepts(&rule, "12345"));
+        assert!(!accepts(&rule, "1234"));
+        assert!(!accepts(&rule, "123456"));
+        assert!(!accepts(&rule, "abcde"));
+        assert_eq!(
+            Err("Please enter a zip code".to_string()),
+            rule.check("1234")
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_invalid_pattern() {
+        assert!(Rule::regex("(", "message").is_err());
+    }
+
+    #[test]
+    fn checked() {
+        let rule = Rule::checked();
+        assert!(accepts(&rule, "true"));
+        assert!(!accepts(&rule, "false"));
+        assert!(!accepts(&rule, ""));
+    }
+
+    #[test]
+    fn custom() {
+        let rule = Rule::custom(|value| {
+            if value == "yes" {
+                Ok(())
+            } else {
+                Err("Say yes".to_string())
+            }
+        });
+        assert_eq!(Ok(()), rule.check("yes"));
+        assert_eq!(Err("Say yes".to_string()), rule.check("no"));
+    }
+
+    #[test]
+    fn form_values() {
+        let values = FormValues {
+            values: [
+                (
+                    "age".to_string(),
+                    FieldValue {
+                        texts: vec!["42".to_string()],
+                        typed: Rc::new("42".to_string()),
+                    },
+                ),
+                (
+                    "colors".to_string(),
+                    FieldValue {
+                        texts: vec!["red".to_string(), "blue".to_string()],
+                        typed: Rc::new(vec![1, 2]),
+                    },
+                ),
+                (
+                    "color".to_string(),
+                    FieldValue {
+                        texts: vec![],
+                        typed: Rc::new(None::<i32>),
+                    },
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        assert_eq!(Some("42"), values.get("age"));
+        assert_eq!(Some(42), values.parse::<u32>("age"));
+        assert_eq!(Some("42".to_string()), values.get_typed::<String>("age"));
+        assert_eq!(None, values.get_typed::<u32>("age"));
+        assert_eq!(Some("red"), values.get("colors"));
+        assert_eq!(
+            Some(&["red".to_string(), "blue".to_string()][..]),
+            values.get_all("colors")
+        );
+        assert_eq!(Some(vec![1, 2]), values.get_typed::<Vec<i32>>("colors"));
+        assert_eq!(Some(""), values.get("color"));
+        assert_eq!(Some(None), values.get_typed::<Option<i32>>("color"));
+        assert_eq!(None, values.get("unknown"));
+        assert_eq!(None, values.get_all("unknown"));
+        assert_eq!(None, values.get_typed::<String>("unknown"));
+    }
+}
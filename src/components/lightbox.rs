@@ -0,0 +1,245 @@
+use leptos::{html::Div, *};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+/// An image shown in a [Lightbox]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LightboxImage {
+    /// The source URL of the thumbnail shown in the gallery
+    pub thumbnail_src: String,
+
+    /// The source URL of the full size image shown when the lightbox is open
+    pub src: String,
+
+    /// A caption shown below the full size image
+    pub caption: String,
+
+    /// An `alt` text for accessibility
+    pub alt: String,
+}
+
+/// Options for a [Lightbox].
+#[derive(Clone)]
+pub struct LightboxOptions {
+    /// How much the image is zoomed in per step. The image can be zoomed in up to a factor of 3.
+    pub zoom_level: f64,
+
+    /// Callback that is called with the index of the newly shown image after the user navigated to another image.
+    pub on_slide: Option<Callback<usize>>,
+}
+
+impl Default for LightboxOptions {
+    fn default() -> Self {
+        Self {
+            zoom_level: 1.0,
+            on_slide: None,
+        }
+    }
+}
+
+/// A Lightbox component, i.e. a gallery of thumbnails that opens the full size images in a fullscreen overlay.
+///
+/// The overlay supports zooming, fullscreen mode and keyboard navigation (arrow keys, home, end and escape).
+///
+/// See [Tailwind Elements: Lightbox](https://tailwind-elements.com/docs/standard/components/lightbox/)
+#[derive(Clone, Copy)]
+pub struct Lightbox {
+    lightbox_impl: StoredValue<Rc<LightboxImpl>>,
+}
+
+impl Lightbox {
+    /// Create a new lightbox. This returns a handle to open or close the lightbox and a view showing the
+    /// thumbnail gallery that needs to be added to the page. Clicking a thumbnail opens the lightbox at that image.
+    pub fn new(
+        images: impl Into<MaybeSignal<Vec<LightboxImage>>>,
+        options: LightboxOptions,
+    ) -> (Self, impl IntoView) {
+        let (is_open, set_is_open) = create_signal(false);
+        let lightbox_impl = Rc::new(LightboxImpl {
+            jslightbox: RefCell::new(None),
+            is_open,
+            set_is_open,
+        });
+        let lightbox_impl = store_value(lightbox_impl);
+        let lightbox = Self { lightbox_impl };
+        let view = view! {
+            <LightboxView lightbox=lightbox images=images.into() options=options />
+        };
+        (lightbox, view)
+    }
+
+    /// Open the lightbox, showing the image with the given index.
+    pub fn open(&self, index: usize) {
+        self.lightbox_impl.with_value(|l| l.open(index));
+    }
+
+    /// Close the lightbox.
+    pub fn close(&self) {
+        self.lightbox_impl.with_value(|l| l.close());
+    }
+
+    /// A signal that is `true` while the lightbox is open.
+    pub fn is_open(&self) -> ReadSignal<bool> {
+        self.lightbox_impl.with_value(|l| l.is_open)
+    }
+}
+
+struct LightboxImpl {
+    jslightbox: RefCell<Option<JsLightbox>>,
+    is_open: ReadSignal<bool>,
+    set_is_open: WriteSignal<bool>,
+}
+
+impl LightboxImpl {
+    pub fn open(&self, index: usize) {
+        let jslightbox = self.jslightbox.borrow();
+        let Some(jslightbox) = jslightbox.as_ref() else {
+            panic!("Tried to open a lightbox but its view is not added to the page");
+        };
+        jslightbox.open(index);
+    }
+
+    pub fn close(&self) {
+        let jslightbox = self.jslightbox.borrow();
+        let Some(jslightbox) = jslightbox.as_ref() else {
+            panic!("Tried to close a lightbox but its view is not added to the page");
+        };
+        jslightbox.close();
+    }
+
+    fn active_image(&self) -> Option<usize> {
+        self.jslightbox
+            .borrow()
+            .as_ref()
+            .map(|jslightbox| jslightbox.active_img())
+    }
+}
+
+#[component]
+fn LightboxView(
+    lightbox: Lightbox,
+    images: MaybeSignal<Vec<LightboxImage>>,
+    options: LightboxOptions,
+) -> impl IntoView {
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let lightbox_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = lightbox_ref() {
+            let jsoptions = JsLightboxOptions {
+                zoom_level: options.zoom_level,
+            };
+            let jslightbox =
+                JsLightbox::new(&element, serde_wasm_bindgen::to_value(&jsoptions).unwrap());
+            lightbox
+                .lightbox_impl
+                .with_value(|l| *l.jslightbox.borrow_mut() = Some(jslightbox));
+
+            let on_open: Closure<dyn FnMut()> = Closure::new(move || {
+                lightbox.lightbox_impl.with_value(|l| (l.set_is_open)(true));
+            });
+            let on_close: Closure<dyn FnMut()> = Closure::new(move || {
+                lightbox
+                    .lightbox_impl
+                    .with_value(|l| (l.set_is_open)(false));
+            });
+            // Tailwind Elements only updates the active image after triggering `slide`, so we listen to `slided`.
+            let on_slide = options.on_slide.clone();
+            let on_slided: Closure<dyn FnMut()> = Closure::new(move || {
+                if let Some(on_slide) = &on_slide {
+                    if let Some(index) = lightbox.lightbox_impl.with_value(|l| l.active_image()) {
+                        on_slide(index);
+                    }
+                }
+            });
+
+            te_lightbox_add_event_listener(&element, "open.te.lightbox", &on_open);
+            te_lightbox_add_event_listener(&element, "close.te.lightbox", &on_close);
+            te_lightbox_add_event_listener(&element, "slided.te.lightbox", &on_slided);
+
+            // We need to copy the Rc and capture it because the stored value may already be un-stored in `on_cleanup`.
+            let lightbox_impl = lightbox.lightbox_impl.with_value(Rc::clone);
+            on_cleanup(move || {
+                let jslightbox = lightbox_impl.jslightbox.borrow_mut().take();
+                if let Some(jslightbox) = jslightbox {
+                    jslightbox.dispose();
+                }
+                std::mem::drop(on_open);
+                std::mem::drop(on_close);
+                std::mem::drop(on_slided);
+            });
+        }
+    });
+
+    // We don't set `data-te-lightbox-init` because Tailwind Elements would then open the lightbox a second time
+    // when a thumbnail is clicked. Tailwind Elements finds the images itself when the lightbox is opened.
+    view! {
+        <div ref=lightbox_ref class="grid grid-cols-2 gap-4 md:grid-cols-3">
+            {move || {
+                images()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, image)| {
+                        view! {
+                            <img
+                                src=image.thumbnail_src
+                                data-te-img=image.src
+                                data-te-caption=image.caption
+                                alt=image.alt
+                                class="w-full cursor-zoom-in rounded shadow-sm data-[te-lightbox-disabled]:cursor-auto"
+                                on:click=move |_| lightbox.open(index)
+                            />
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Lightbox)]
+    type JsLightbox;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Lightbox, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsLightbox;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Lightbox, final)]
+    fn open(this: &JsLightbox, index: usize);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Lightbox, final)]
+    fn close(this: &JsLightbox);
+
+    #[wasm_bindgen(method, getter, js_namespace = te, js_class = Lightbox, js_name = activeImg, final)]
+    fn active_img(this: &JsLightbox) -> usize;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Lightbox, final)]
+    fn dispose(this: &JsLightbox);
+}
+
+#[wasm_bindgen(
+    inline_js = "export function te_lightbox_add_event_listener(lightbox_html_elem, event_name, callback) { lightbox_html_elem.addEventListener(event_name, (event) => {
+        callback();
+    }); }"
+)]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_lightbox_add_event_listener(
+        lightbox: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsLightboxOptions {
+    zoom_level: f64,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/components/lightbox/#docsTabsAPI
+}
@@ -2,11 +2,13 @@
 //!
 //! See [Tailwind Elements: Components](https://tailwind-elements.com/docs/standard/components/accordion/).
 
+mod unique_id;
+
 mod button;
 pub use button::{Button, ButtonStyle};
 
 mod dropdown;
-pub use dropdown::Dropdown;
+pub use dropdown::{Dropdown, DropdownAutoClose, DropdownItem, DropdownPlacement, DropdownTrigger};
 
 pub mod forms;
 pub mod navigation;
@@ -17,8 +19,40 @@ pub use spinner::{Spinner, SpinnerSize};
 mod modal;
 pub use modal::{Modal, ModalBody, ModalFooter, ModalHeader};
 
+mod offcanvas;
+pub use offcanvas::{
+    Offcanvas, OffcanvasBody, OffcanvasHeader, OffcanvasOptions, OffcanvasPlacement,
+};
+
 mod carousel;
 pub use carousel::{Carousel, CarouselImage};
 
+mod lightbox;
+pub use lightbox::{Lightbox, LightboxImage, LightboxOptions};
+
 mod alert;
 pub use alert::{Alert, AlertType};
+
+mod collapse;
+pub use collapse::Collapse;
+
+mod accordion;
+pub use accordion::{Accordion, AccordionItem};
+
+mod tooltip;
+pub use tooltip::{Tooltip, TooltipPlacement, TooltipTitle, TooltipTrigger};
+
+mod popover;
+pub use popover::{Popover, PopoverBody, PopoverHeader};
+
+mod popconfirm;
+pub use popconfirm::{Popconfirm, PopconfirmIcon, PopconfirmMode};
+
+mod toast;
+pub use toast::{use_toaster, Toast, ToastId, ToastPosition, ToastProvider, ToastSpec, Toaster};
+
+mod stepper;
+pub use stepper::{Step, Stepper, StepperMode, StepperOptions};
+
+mod chips;
+pub use chips::{Chip, ChipsInput};
@@ -179,7 +179,7 @@ pub fn ModalFooter(children: Children) -> impl IntoView {
 }
 
 #[component]
-fn CloseButton() -> impl IntoView {
+pub(super) fn CloseButton() -> impl IntoView {
     view! {
         <button
             type="button"
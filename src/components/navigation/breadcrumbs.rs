@@ -0,0 +1,181 @@
+use leptos::*;
+
+/// A Breadcrumbs component, i.e. a list of links showing where the current page is in the hierarchy of the app.
+///
+/// `items` are `(label, href)` pairs, starting at the top of the hierarchy. The last item is the current page
+/// and isn't rendered as a link.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Breadcrumbs items=vec![
+///         ("Home".to_string(), "/".to_string()),
+///         ("Library".to_string(), "/library".to_string()),
+///         ("Data".to_string(), "/library/data".to_string()),
+///     ] />
+/// }
+/// ```
+///
+/// When using `leptos_router`, the items can be generated from the current path with [breadcrumbs_from_path]:
+/// ```ignore
+/// let pathname = use_location().pathname;
+/// view! {
+///     <Breadcrumbs items=Signal::derive(move || pathname.with(|path| breadcrumbs_from_path(path))) />
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Breadcrumbs](https://tailwind-elements.com/docs/standard/navigation/breadcrumbs/)
+#[component]
+pub fn Breadcrumbs(
+    /// The `(label, href)` pairs of the items.
+    #[prop(into)]
+    items: MaybeSignal<Vec<(String, String)>>,
+    /// The separator shown between the items.
+    #[prop(into, default = "/".into())]
+    separator: MaybeSignal<String>,
+) -> impl IntoView {
+    view! {
+        <nav class="w-full rounded-md" aria-label="breadcrumb">
+            <ol class="list-reset flex">
+                {move || {
+                    let items = items();
+                    let num_items = items.len();
+                    items
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, (label, href))| {
+                            let separator = separator.clone();
+                            let is_last = index + 1 == num_items;
+                            view! {
+                                {(index > 0).then(|| view! {
+                                    <li>
+                                        <span class="mx-2 text-neutral-500 dark:text-neutral-400">{separator}</span>
+                                    </li>
+                                })}
+                                {if is_last {
+                                    view! {
+                                        <li class="text-neutral-500 dark:text-neutral-400" aria-current="page">
+                                            {label}
+                                        </li>
+                                    }
+                                } else {
+                                    view! {
+                                        <li>
+                                            <a
+                                                href=href
+                                                class="text-primary transition duration-150 ease-in-out hover:text-primary-600 focus:text-primary-600 active:text-primary-700 dark:text-primary-400 dark:hover:text-primary-500 dark:focus:text-primary-500 dark:active:text-primary-600"
+                                            >
+                                                {label}
+                                            </a>
+                                        </li>
+                                    }
+                                }}
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ol>
+        </nav>
+    }
+}
+
+/// Generate the items of a [Breadcrumbs] component from a path like `/library/user-data`.
+///
+/// The first item is "Home", linking to `/`, followed by one item per segment of the path.
+/// Labels are generated from the segments by replacing dashes and underscores with spaces and
+/// capitalizing the first letter, e.g. `user-data` becomes "User data".
+pub fn breadcrumbs_from_path(path: &str) -> Vec<(String, String)> {
+    let path = path.split(['?', '#']).next().unwrap_or("");
+    let mut href = String::new();
+    let mut items = vec![("Home".to_string(), "/".to_string())];
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        href.push('/');
+        href.push_str(segment);
+        items.push((segment_label(segment), href.clone()));
+    }
+    items
+}
+
+fn segment_label(segment: &str) -> String {
+    let label = segment.replace(['-', '_'], " ");
+    let mut chars = label.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => label,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(label, href)| (label.to_string(), href.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(items(&[("Home", "/")]), breadcrumbs_from_path("/"));
+        assert_eq!(items(&[("Home", "/")]), breadcrumbs_from_path(""));
+    }
+
+    #[test]
+    fn nested_path() {
+        assert_eq!(
+            items(&[
+                ("Home", "/"),
+                ("Library", "/library"),
+                ("User data", "/library/user-data"),
+            ]),
+            breadcrumbs_from_path("/library/user-data")
+        );
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(
+            items(&[
+                ("Home", "/"),
+                ("My files", "/my_files"),
+                ("Über uns", "/my_files/über-uns")
+            ]),
+            breadcrumbs_from_path("/my_files/über-uns")
+        );
+    }
+
+    #[test]
+    fn trailing_and_duplicate_slashes() {
+        assert_eq!(
+            items(&[("Home", "/"), ("A", "/a"), ("B", "/a/b")]),
+            breadcrumbs_from_path("/a//b/")
+        );
+    }
+
+    #[test]
+    fn strips_query() {
+        assert_eq!(
+            items(&[("Home", "/"), ("Search", "/search")]),
+            breadcrumbs_from_path("/search?q=a/b")
+        );
+    }
+
+    #[test]
+    fn strips_fragment() {
+        assert_eq!(
+            items(&[("Home", "/"), ("Docs", "/docs")]),
+            breadcrumbs_from_path("/docs#installation/usage")
+        );
+    }
+
+    #[test]
+    fn strips_query_and_fragment() {
+        assert_eq!(
+            items(&[("Home", "/"), ("Library", "/library")]),
+            breadcrumbs_from_path("/library/?page=2#top")
+        );
+    }
+}
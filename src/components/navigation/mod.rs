@@ -3,4 +3,22 @@
 //! See [Tailwind Elements: Navigation](https://tailwind-elements.com/docs/standard/navigation/breadcrumbs/)
 
 mod sidenav;
-pub use sidenav::{Sidenav, SidenavItem};
+pub use sidenav::{
+    Sidenav, SidenavBadge, SidenavDivider, SidenavGroup, SidenavHandle, SidenavHeader, SidenavIcon,
+    SidenavItem, SidenavMode, SidenavPlacement, SidenavPosition,
+};
+
+mod navbar;
+pub use navbar::{Navbar, NavbarBrand, NavbarItem, NavbarLeft, NavbarPosition, NavbarRight};
+
+mod breadcrumbs;
+pub use breadcrumbs::{breadcrumbs_from_path, Breadcrumbs};
+
+mod pagination;
+pub use pagination::{Pagination, PaginationSize};
+
+mod tabs;
+pub use tabs::{Tab, TabPanel, Tabs, TabsLayout};
+
+mod scrollspy;
+pub use scrollspy::{use_scrollspy, Scrollspy};
@@ -0,0 +1,224 @@
+use leptos::{html::Div, *};
+use std::rc::Rc;
+
+use crate::components::collapse::{JsCollapse, JsCollapseOptions};
+
+/// How a [Navbar] is positioned on the page.
+///
+/// See [Tailwind Elements: Navbar#Placement](https://tailwind-elements.com/docs/standard/navigation/navbar/#placement)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavbarPosition {
+    /// The navbar scrolls with the page
+    #[default]
+    Static,
+    /// The navbar scrolls with the page until it reaches the top of the window and then sticks there
+    Sticky,
+    /// The navbar is always at the top of the window. Note that it covers the top of the page content.
+    FixedTop,
+    /// The navbar is always at the bottom of the window. Note that it covers the bottom of the page content.
+    FixedBottom,
+}
+
+impl NavbarPosition {
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Static => "relative",
+            Self::Sticky => "sticky top-0 z-[1030]",
+            Self::FixedTop => "fixed left-0 right-0 top-0 z-[1030]",
+            Self::FixedBottom => "fixed bottom-0 left-0 right-0 z-[1030]",
+        }
+    }
+}
+
+/// The brand of a [Navbar], usually a logo or the name of the app. Pass it with `<NavbarBrand slot>`.
+#[slot]
+pub struct NavbarBrand {
+    /// The brand, usually a link to the start page
+    children: Children,
+}
+
+/// Items shown on the left side of a [Navbar]. Pass them with `<NavbarLeft slot>`.
+#[slot]
+pub struct NavbarLeft {
+    /// The items, usually [NavbarItem]s
+    children: Children,
+}
+
+/// Items shown on the right side of a [Navbar]. Pass them with `<NavbarRight slot>`.
+#[slot]
+pub struct NavbarRight {
+    /// The items, usually [NavbarItem]s
+    children: Children,
+}
+
+#[derive(Clone, Copy)]
+struct NavbarContext {
+    set_expanded: WriteSignal<bool>,
+}
+
+/// A Navbar component, i.e. a bar at the top of the page with a brand and navigation links.
+///
+/// On small screens, the items are collapsed into a menu that is opened with a hamburger button.
+///
+/// Items can be [NavbarItem]s or any other list items, e.g. an `<li>` containing a [Dropdown](crate::components::Dropdown).
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Navbar position=NavbarPosition::Sticky>
+///         <NavbarBrand slot><a href="/">"My App"</a></NavbarBrand>
+///         <NavbarLeft slot>
+///             <NavbarItem href="/">"Home"</NavbarItem>
+///             <NavbarItem href="/about">"About"</NavbarItem>
+///         </NavbarLeft>
+///         <NavbarRight slot>
+///             <li>
+///                 <Dropdown
+///                     label="Account"
+///                     button_style=ButtonStyle::Link
+///                     items=vec![DropdownItem::link("Settings", "/settings")]
+///                 />
+///             </li>
+///         </NavbarRight>
+///     </Navbar>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Navbar](https://tailwind-elements.com/docs/standard/navigation/navbar/)
+#[component]
+pub fn Navbar(
+    /// How the navbar is positioned on the page. See [NavbarPosition].
+    #[prop(default = NavbarPosition::Static)]
+    position: NavbarPosition,
+    /// The brand shown at the start of the navbar. See [NavbarBrand].
+    #[prop(optional)]
+    navbar_brand: Option<NavbarBrand>,
+    /// The items shown on the left side. See [NavbarLeft].
+    #[prop(optional)]
+    navbar_left: Option<NavbarLeft>,
+    /// The items shown on the right side. See [NavbarRight].
+    #[prop(optional)]
+    navbar_right: Option<NavbarRight>,
+) -> impl IntoView {
+    // Whether the menu is expanded on small screens. On large screens, the items are always shown.
+    let (expanded, set_expanded) = create_signal(false);
+    provide_context(NavbarContext { set_expanded });
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let collapse_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = collapse_ref() {
+            let options = JsCollapseOptions { toggle: false };
+            let jscollapse = Rc::new(JsCollapse::new(
+                &element,
+                serde_wasm_bindgen::to_value(&options).unwrap(),
+            ));
+            {
+                let jscollapse = Rc::clone(&jscollapse);
+                create_effect(move |prev: Option<()>| {
+                    let expanded = expanded();
+                    // The menu is initially collapsed, no need to animate that
+                    if prev.is_some() {
+                        if expanded {
+                            jscollapse.show();
+                        } else {
+                            jscollapse.hide();
+                        }
+                    }
+                });
+            }
+            on_cleanup(move || jscollapse.dispose());
+        }
+    });
+
+    // TODO Allow choosing the breakpoint at which the items are collapsed into the menu
+    view! {
+        <nav
+            class=format!("{} flex w-full flex-wrap items-center justify-between bg-[#FBFBFB] py-2 text-neutral-500 shadow-lg hover:text-neutral-700 focus:text-neutral-700 dark:bg-neutral-600 lg:py-4", position.class())
+            data-te-navbar-ref
+        >
+            <div class="flex w-full flex-wrap items-center justify-between px-3">
+                {navbar_brand.map(|brand| view! {
+                    <div class="mx-2 my-1 flex items-center text-xl text-neutral-900 hover:text-neutral-900 focus:text-neutral-900 dark:text-neutral-200 lg:mb-0 lg:mt-0">
+                        {(brand.children)()}
+                    </div>
+                })}
+                <button
+                    class="block border-0 bg-transparent px-2 text-neutral-500 hover:no-underline hover:shadow-none focus:no-underline focus:shadow-none focus:outline-none focus:ring-0 dark:text-neutral-200 lg:hidden"
+                    type="button"
+                    aria-expanded=move || expanded().to_string()
+                    aria-label="Toggle navigation"
+                    on:click=move |_| set_expanded.update(|expanded| *expanded = !*expanded)
+                >
+                    <span class="[&>svg]:w-7">
+                        <HamburgerIcon />
+                    </span>
+                </button>
+                <div
+                    ref=collapse_ref
+                    class="!visible hidden flex-grow basis-[100%] items-center lg:!flex lg:basis-auto"
+                    data-te-collapse-item
+                >
+                    <ul class="list-style-none mr-auto flex flex-col pl-0 lg:flex-row" data-te-navbar-nav-ref>
+                        {navbar_left.map(|left| (left.children)())}
+                    </ul>
+                    <ul class="list-style-none ml-auto flex flex-col pl-0 lg:flex-row" data-te-navbar-nav-ref>
+                        {navbar_right.map(|right| (right.children)())}
+                    </ul>
+                </div>
+            </div>
+        </nav>
+    }
+}
+
+/// A link in a [Navbar]. Clicking it closes the menu on small screens.
+#[component]
+pub fn NavbarItem(
+    /// The target of the link.
+    #[prop(into)]
+    href: MaybeSignal<String>,
+    /// Whether the link is highlighted as the current page.
+    #[prop(into, default = false.into())]
+    active: MaybeSignal<bool>,
+    /// The content of the link, usually its text.
+    children: Children,
+) -> impl IntoView {
+    let navbar = use_context::<NavbarContext>();
+
+    view! {
+        <li class="my-4 pl-2 lg:my-0 lg:pl-2 lg:pr-1" data-te-nav-item-ref>
+            <a
+                class="p-0 text-neutral-500 transition duration-200 hover:text-neutral-700 hover:ease-in-out focus:text-neutral-700 disabled:text-black/30 motion-reduce:transition-none dark:text-neutral-200 dark:hover:text-neutral-400 dark:focus:text-neutral-400 lg:px-2 [&.active]:text-black/90 dark:[&.active]:text-neutral-400"
+                class:active=active
+                href=href
+                aria-current=move || active().then_some("page")
+                data-te-nav-link-ref
+                on:click=move |_| {
+                    if let Some(navbar) = navbar {
+                        navbar.set_expanded.set(false);
+                    }
+                }
+            >
+                {children()}
+            </a>
+        </li>
+    }
+}
+
+#[component]
+fn HamburgerIcon() -> impl IntoView {
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox="0 0 24 24"
+            fill="currentColor"
+            class="h-7 w-7">
+            <path
+                fill-rule="evenodd"
+                d="M3 6.75A.75.75 0 013.75 6h16.5a.75.75 0 010 1.5H3.75A.75.75 0 013 6.75zM3 12a.75.75 0 01.75-.75h16.5a.75.75 0 010 1.5H3.75A.75.75 0 013 12zm0 5.25a.75.75 0 01.75-.75h16.5a.75.75 0 010 1.5H3.75a.75.75 0 01-.75-.75z"
+                clip-rule="evenodd"
+            />
+        </svg>
+    }
+}
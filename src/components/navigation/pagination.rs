@@ -0,0 +1,289 @@
+use leptos::*;
+
+/// The size of a [Pagination].
+///
+/// See [Tailwind Elements: Pagination#Sizes](https://tailwind-elements.com/docs/standard/navigation/pagination/#sizes)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaginationSize {
+    /// Small Pagination
+    Small,
+    /// Medium Pagination
+    #[default]
+    Medium,
+    /// Large Pagination
+    Large,
+}
+
+impl PaginationSize {
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Small => "px-2 py-1 text-xs",
+            Self::Medium => "px-3 py-1.5 text-sm",
+            Self::Large => "px-4 py-2 text-lg",
+        }
+    }
+}
+
+/// A Pagination component, i.e. a row of buttons to switch between the pages of a long list.
+///
+/// Pages are numbered starting at 1. If there are many pages, only the first and last page and the pages
+/// around the current page are shown, and the others are replaced by an ellipsis.
+///
+/// Example
+/// -------
+/// ```ignore
+/// let (page, set_page) = create_signal(1);
+/// view! {
+///     <Pagination current_page=page page_count=20 on_change=set_page />
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Pagination](https://tailwind-elements.com/docs/standard/navigation/pagination/)
+#[component]
+pub fn Pagination<OnChangeFn: Fn(usize) + 'static>(
+    /// This signal is read to decide which page is the current page.
+    /// Whenever this signal changes, the pagination is updated.
+    /// But note that clicking a page doesn't change it by itself, it only calls `on_change`,
+    /// which is expected to update the `current_page` signal.
+    #[prop(into)]
+    current_page: Signal<usize>,
+    /// The number of pages.
+    #[prop(into)]
+    page_count: MaybeSignal<usize>,
+    /// Callback that is called with the number of a page when the user clicks it.
+    on_change: OnChangeFn,
+    /// How many pages are shown on each side of the current page before the ellipsis.
+    #[prop(default = 1)]
+    siblings: usize,
+    /// Whether to show buttons to jump to the first and last page.
+    #[prop(default = false)]
+    show_first_last: bool,
+    /// The size of the pagination. See [PaginationSize].
+    #[prop(default = PaginationSize::Medium)]
+    size: PaginationSize,
+) -> impl IntoView {
+    let on_change = store_value(on_change);
+    let change = move |page: usize| {
+        if page != current_page.get_untracked() {
+            on_change.with_value(|on_change| on_change(page));
+        }
+    };
+
+    let nav_button = move |label: &'static str,
+                           target: Box<dyn Fn() -> usize>,
+                           enabled: Signal<bool>| {
+        view! {
+            <li>
+                <button
+                    type="button"
+                    class=format!("relative block rounded bg-transparent text-neutral-600 transition-all duration-300 hover:bg-neutral-100 disabled:pointer-events-none disabled:text-neutral-500 dark:text-white dark:hover:bg-neutral-700 dark:hover:text-white dark:disabled:text-neutral-400 {}", size.class())
+                    disabled=move || !enabled()
+                    on:click=move |_| change(target())
+                >
+                    {label}
+                </button>
+            </li>
+        }
+    };
+    let has_prev = Signal::derive(move || current_page() > 1);
+    let has_next = Signal::derive(move || current_page() < page_count());
+
+    view! {
+        <nav aria-label="Pagination">
+            <ul class="list-style-none flex">
+                {show_first_last.then(|| nav_button("First", Box::new(|| 1), has_prev))}
+                {nav_button("Previous", Box::new(move || current_page.get_untracked() - 1), has_prev)}
+                {move || {
+                    page_items(current_page(), page_count(), siblings)
+                        .into_iter()
+                        .map(|item| match item {
+                            PageItem::Page(page) if page == current_page() => view! {
+                                <li aria-current="page">
+                                    <button
+                                        type="button"
+                                        class=format!("relative block rounded bg-primary-100 font-medium text-primary-700 transition-all duration-300 {}", size.class())
+                                    >
+                                        {page}
+                                        <span class="absolute -m-px h-px w-px overflow-hidden whitespace-nowrap border-0 p-0 [clip:rect(0,0,0,0)]">
+                                            "(current)"
+                                        </span>
+                                    </button>
+                                </li>
+                            },
+                            PageItem::Page(page) => view! {
+                                <li>
+                                    <button
+                                        type="button"
+                                        class=format!("relative block rounded bg-transparent text-neutral-600 transition-all duration-300 hover:bg-neutral-100 dark:text-white dark:hover:bg-neutral-700 dark:hover:text-white {}", size.class())
+                                        on:click=move |_| change(page)
+                                    >
+                                        {page}
+                                    </button>
+                                </li>
+                            },
+                            PageItem::Ellipsis => view! {
+                                <li>
+                                    <span class=format!("relative block text-neutral-500 dark:text-neutral-400 {}", size.class())>
+                                        "…"
+                                    </span>
+                                </li>
+                            },
+                        })
+                        .collect_view()
+                }}
+                {nav_button("Next", Box::new(move || current_page.get_untracked() + 1), has_next)}
+                {show_first_last.then(|| nav_button("Last", Box::new(move || page_count.get_untracked()), has_next))}
+            </ul>
+        </nav>
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+// The first and last page are always shown, as well as `siblings` pages on each side of the current page.
+// An ellipsis only replaces two or more pages, a single page is shown instead.
+fn page_items(current_page: usize, page_count: usize, siblings: usize) -> Vec<PageItem> {
+    if page_count == 0 {
+        return vec![];
+    }
+    let around_current = current_page.saturating_sub(siblings).max(1)
+        ..=current_page.saturating_add(siblings).min(page_count);
+    let mut shown_pages: Vec<usize> = std::iter::once(1)
+        .chain(around_current)
+        .chain(std::iter::once(page_count))
+        .collect();
+    shown_pages.dedup();
+
+    let mut items = Vec::new();
+    let mut last_shown = 0;
+    for page in shown_pages {
+        if page <= last_shown {
+            continue;
+        }
+        match page - last_shown {
+            1 => {}
+            2 => items.push(PageItem::Page(page - 1)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+        items.push(PageItem::Page(page));
+        last_shown = page;
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PageItem::{Ellipsis, Page};
+
+    #[test]
+    fn no_pages() {
+        assert_eq!(Vec::<PageItem>::new(), page_items(1, 0, 1));
+    }
+
+    #[test]
+    fn single_page() {
+        assert_eq!(vec![Page(1)], page_items(1, 1, 1));
+        assert_eq!(vec![Page(1)], page_items(1, 1, 0));
+    }
+
+    #[test]
+    fn two_pages() {
+        assert_eq!(vec![Page(1), Page(2)], page_items(1, 2, 0));
+        assert_eq!(vec![Page(1), Page(2)], page_items(2, 2, 0));
+    }
+
+    #[test]
+    fn current_at_start() {
+        assert_eq!(
+            vec![Page(1), Page(2), Ellipsis, Page(10)],
+            page_items(1, 10, 1)
+        );
+    }
+
+    #[test]
+    fn current_at_end() {
+        assert_eq!(
+            vec![Page(1), Ellipsis, Page(9), Page(10)],
+            page_items(10, 10, 1)
+        );
+    }
+
+    #[test]
+    fn current_in_middle() {
+        assert_eq!(
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(10)
+            ],
+            page_items(5, 10, 1)
+        );
+    }
+
+    #[test]
+    fn no_siblings() {
+        assert_eq!(
+            vec![Page(1), Ellipsis, Page(5), Ellipsis, Page(10)],
+            page_items(5, 10, 0)
+        );
+        assert_eq!(vec![Page(1), Ellipsis, Page(10)], page_items(1, 10, 0));
+        assert_eq!(vec![Page(1), Ellipsis, Page(10)], page_items(10, 10, 0));
+    }
+
+    #[test]
+    fn single_skipped_page_is_shown() {
+        assert_eq!(
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(10)
+            ],
+            page_items(4, 10, 1)
+        );
+        assert_eq!(
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ],
+            page_items(7, 10, 1)
+        );
+        assert_eq!(
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Page(6),
+                Page(7)
+            ],
+            page_items(4, 7, 1)
+        );
+    }
+
+    #[test]
+    fn siblings_beyond_bounds() {
+        assert_eq!(
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)],
+            page_items(3, 5, 10)
+        );
+    }
+}
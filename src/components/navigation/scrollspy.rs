@@ -0,0 +1,197 @@
+use leptos::{html::Div, *};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+/// A Scrollspy component. It highlights the link of the section of the page that is currently scrolled into view.
+///
+/// The children are expected to contain links to sections of the page, e.g. `href="#installation"`.
+/// Links that should be highlighted need the `data-te-nav-link-ref` attribute. A [SidenavItem](super::SidenavItem)
+/// with such a link already has it, so wrapping a [Sidenav](super::Sidenav) into a [Scrollspy] highlights the
+/// currently visible section automatically.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Scrollspy>
+///         <Sidenav content_id="content">
+///             <SidenavItem href="#installation">"Installation"</SidenavItem>
+///             <SidenavItem href="#usage">"Usage"</SidenavItem>
+///         </Sidenav>
+///     </Scrollspy>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Scrollspy](https://tailwind-elements.com/docs/standard/navigation/scrollspy/)
+#[component]
+pub fn Scrollspy(
+    /// How many pixels below the top of the window a section has to reach to become active.
+    #[prop(into, default = 10.0.into())]
+    offset: MaybeSignal<f64>,
+    /// Callback that is called with the id of a section when it becomes active.
+    #[prop(into, optional)]
+    on_activate: Option<Callback<String>>,
+    /// The navigation containing the links to the sections.
+    children: Children,
+) -> impl IntoView {
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            // TODO Allow spying on the scroll position of other elements than the page body
+            let options = JsScrollspyOptions {
+                target: JsValue::from((*element).clone()),
+                offset: offset(),
+            };
+            let jsscrollspy = JsScrollspy::new(
+                &document().body().unwrap(),
+                serde_wasm_bindgen::to_value(&options).unwrap(),
+            );
+
+            let on_activate = on_activate.clone();
+            let on_activate: Closure<dyn FnMut(String)> = Closure::new(move |id: String| {
+                if let Some(on_activate) = &on_activate {
+                    on_activate(id);
+                }
+            });
+            let listener = te_scrollspy_add_event_listener(&element, &on_activate);
+
+            on_cleanup(move || {
+                te_scrollspy_remove_event_listener(&listener);
+                jsscrollspy.dispose();
+                std::mem::drop(on_activate);
+            });
+        }
+    });
+
+    view! {
+        <div ref=element_ref>
+            {children()}
+        </div>
+    }
+}
+
+/// Tracks which of the given sections of the page is currently scrolled into view.
+///
+/// `targets` are the ids of the sections, `offset` is how many pixels below the top of the window a section has
+/// to reach to become active. The returned signal contains the id of the active section, or `None` if the page
+/// is scrolled above the first section.
+///
+/// This doesn't highlight any links by itself, see [Scrollspy] for that.
+pub fn use_scrollspy(
+    targets: impl Into<MaybeSignal<Vec<String>>>,
+    offset: f64,
+) -> Signal<Option<String>> {
+    let targets = targets.into();
+    let (active, set_active) = create_signal(None);
+    let (scrolled, set_scrolled) = create_signal(());
+
+    let scroll_handle = window_event_listener(ev::scroll, move |_| set_scrolled(()));
+    let resize_handle = window_event_listener(ev::resize, move |_| set_scrolled(()));
+    on_cleanup(move || {
+        scroll_handle.remove();
+        resize_handle.remove();
+    });
+
+    // Effects only run in the browser, so this doesn't try to access the page during server side rendering.
+    create_effect(move |_| {
+        scrolled();
+        let new_active = targets.with(|targets| active_section(targets, offset));
+        if active.with_untracked(|active| active != &new_active) {
+            set_active(new_active);
+        }
+    });
+
+    active.into()
+}
+
+fn active_section(targets: &[String], offset: f64) -> Option<String> {
+    let document = document();
+    let sections: Vec<(&String, f64)> = targets
+        .iter()
+        .filter_map(|id| {
+            let top = document
+                .get_element_by_id(id)?
+                .get_bounding_client_rect()
+                .top();
+            Some((id, top))
+        })
+        .collect();
+
+    // Once the page is scrolled to the bottom, the last section is active even if it is too short to reach the offset.
+    let window = window();
+    let scroll_bottom = window.scroll_y().unwrap_or(0.0)
+        + window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or(0.0);
+    let scroll_height = document
+        .document_element()
+        .map(|element| f64::from(element.scroll_height()))
+        .unwrap_or(0.0);
+    if scroll_bottom >= scroll_height && scroll_height > 0.0 {
+        if let Some((id, _)) = sections.iter().max_by(|(_, a), (_, b)| a.total_cmp(b)) {
+            return Some((*id).clone());
+        }
+    }
+
+    sections
+        .into_iter()
+        .filter(|(_, top)| *top <= offset)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(id, _)| id.clone())
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = ScrollSpy)]
+    type JsScrollspy;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = ScrollSpy, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsScrollspy;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = ScrollSpy, final)]
+    fn dispose(this: &JsScrollspy);
+}
+
+// Tailwind Elements triggers the `activate` event on the window when spying on the page body,
+// so we need to filter out events from other scrollspies on the same page.
+#[wasm_bindgen(inline_js = "
+    export function te_scrollspy_add_event_listener(scrollspy_html_elem, callback) {
+        const listener = (event) => {
+            const target = event.relatedTarget;
+            if (scrollspy_html_elem.querySelector(`[href=\"${target}\"],[data-te-target=\"${target}\"]`)) {
+                callback(target.substring(1));
+            }
+        };
+        window.addEventListener('activate.te.scrollspy', listener);
+        return listener;
+    }
+    export function te_scrollspy_remove_event_listener(listener) {
+        window.removeEventListener('activate.te.scrollspy', listener);
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_scrollspy_add_event_listener(
+        scrollspy: &web_sys::HtmlElement,
+        callback: &Closure<dyn FnMut(String)>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_scrollspy_remove_event_listener(listener: &JsValue);
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsScrollspyOptions {
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    target: JsValue,
+
+    offset: f64,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/navigation/scrollspy/#docsTabsAPI
+}
@@ -1,34 +1,401 @@
 use leptos::{html::Nav, *};
-use serde::{Deserialize, Serialize};
-use wasm_bindgen::prelude::{wasm_bindgen, JsValue};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
 use web_sys::HtmlElement;
 
-// TODO Sidenav can so so much more, see https://tailwind-elements.com/docs/standard/navigation/sidenav
+/// How a [Sidenav] interacts with the content of the page.
+///
+/// See [Tailwind Elements: Sidenav#Modes](https://tailwind-elements.com/docs/standard/navigation/sidenav/#over)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SidenavMode {
+    /// The sidenav is shown on top of the content
+    Over,
+    /// The sidenav pushes the content off the screen
+    Push,
+    /// The sidenav shrinks the content
+    #[default]
+    Side,
+}
+
+impl SidenavMode {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Over => "over",
+            Self::Push => "push",
+            Self::Side => "side",
+        }
+    }
+}
+
+/// The edge of the screen a [Sidenav] is attached to.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SidenavPlacement {
+    /// Attach the sidenav to the left edge
+    #[default]
+    Left,
+    /// Attach the sidenav to the right edge
+    Right,
+}
+
+impl SidenavPlacement {
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Left => "left-0 -translate-x-full",
+            Self::Right => "right-0 translate-x-full",
+        }
+    }
+}
 
+/// How a [Sidenav] is positioned.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SidenavPosition {
+    /// The sidenav is positioned relative to the window and doesn't scroll with the page
+    #[default]
+    Fixed,
+    /// The sidenav is positioned relative to its closest positioned ancestor
+    Absolute,
+}
+
+impl SidenavPosition {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Fixed => "fixed",
+            Self::Absolute => "absolute",
+        }
+    }
+}
+
+/// A handle to show or hide a [Sidenav]. Create it with [SidenavHandle::new] and pass it to the sidenav
+/// with `<Sidenav handle=handle>`.
+#[derive(Clone, Copy)]
+pub struct SidenavHandle {
+    sidenav_impl: StoredValue<Rc<SidenavImpl>>,
+}
+
+impl SidenavHandle {
+    /// Create a new handle. It can only be used once it was passed to a [Sidenav] that is added to the page.
+    pub fn new() -> Self {
+        let (showing, set_showing) = create_signal(false);
+        let sidenav_impl = Rc::new(SidenavImpl {
+            jssidenav: RefCell::new(None),
+            showing,
+            set_showing,
+        });
+        Self {
+            sidenav_impl: store_value(sidenav_impl),
+        }
+    }
+
+    /// Slide the sidenav in.
+    pub fn show(&self) {
+        self.sidenav_impl.with_value(|s| s.show());
+    }
+
+    /// Slide the sidenav out.
+    pub fn hide(&self) {
+        self.sidenav_impl.with_value(|s| s.hide());
+    }
+
+    /// Slide the sidenav in if it is hidden, or out if it is showing.
+    pub fn toggle(&self) {
+        self.sidenav_impl.with_value(|s| s.toggle());
+    }
+
+    /// A signal that is `true` while the sidenav is showing.
+    pub fn showing(&self) -> ReadSignal<bool> {
+        self.sidenav_impl.with_value(|s| s.showing)
+    }
+}
+
+impl Default for SidenavHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct SidenavImpl {
+    jssidenav: RefCell<Option<JsSidenav>>,
+    showing: ReadSignal<bool>,
+    set_showing: WriteSignal<bool>,
+}
+
+impl SidenavImpl {
+    pub fn show(&self) {
+        let jssidenav = self.jssidenav.borrow();
+        let Some(jssidenav) = jssidenav.as_ref() else {
+            panic!("Tried to show a sidenav but it is not added to the page");
+        };
+        jssidenav.show();
+    }
+
+    pub fn hide(&self) {
+        let jssidenav = self.jssidenav.borrow();
+        let Some(jssidenav) = jssidenav.as_ref() else {
+            panic!("Tried to hide a sidenav but it is not added to the page");
+        };
+        jssidenav.hide();
+    }
+
+    pub fn toggle(&self) {
+        let jssidenav = self.jssidenav.borrow();
+        let Some(jssidenav) = jssidenav.as_ref() else {
+            panic!("Tried to toggle a sidenav but it is not added to the page");
+        };
+        jssidenav.toggle();
+    }
+
+    fn set_slim_collapsed(&self, slim_collapsed: bool) {
+        if let Some(jssidenav) = self.jssidenav.borrow().as_ref() {
+            if jssidenav.slim_collapsed() != slim_collapsed {
+                jssidenav.toggle_slim();
+            }
+        }
+    }
+}
+
+/// A Sidenav component, i.e. a navigation menu attached to the left or right edge of the screen.
+///
+/// The sidenav can either be controlled with the `open` signal or with a [SidenavHandle].
+///
+/// Example
+/// -------
+/// ```ignore
+/// let (open, set_open) = create_signal(true);
+/// view! {
+///     <Sidenav
+///         content_id="content"
+///         mode=SidenavMode::Push
+///         hide_below=768
+///         open=open
+///         on_show=move |()| set_open(true)
+///         on_hide=move |()| set_open(false)
+///     >
+///         <SidenavItem href="/">"Home"</SidenavItem>
+///         <SidenavItem href="/settings">"Settings"</SidenavItem>
+///     </Sidenav>
+///     <div id="content">...</div>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Sidenav](https://tailwind-elements.com/docs/standard/navigation/sidenav/)
 #[component]
-pub fn Sidenav(#[prop(into)] content_id: String, children: Children) -> impl IntoView {
+pub fn Sidenav(
+    /// The id of the element containing the content of the page.
+    /// In [SidenavMode::Push] and [SidenavMode::Side], this element is moved or shrunk to make room for the sidenav.
+    #[prop(into, optional)]
+    content_id: Option<String>,
+    /// How the sidenav interacts with the content of the page. See [SidenavMode].
+    #[prop(into, default = SidenavMode::Side.into())]
+    mode: MaybeSignal<SidenavMode>,
+    /// The edge of the screen the sidenav is attached to. See [SidenavPlacement].
+    #[prop(default = SidenavPlacement::Left)]
+    placement: SidenavPlacement,
+    /// How the sidenav is positioned. See [SidenavPosition].
+    #[prop(default = SidenavPosition::Fixed)]
+    position: SidenavPosition,
+    /// The width of the sidenav in pixels.
+    #[prop(default = 240)]
+    width: u32,
+    /// Whether the sidenav can be collapsed to a slim version of `slim_width` pixels.
+    /// Content that should be hidden in the slim version needs the `data-te-sidenav-slim="false"` attribute,
+    /// content that should only be shown in the slim version needs `data-te-sidenav-slim="true"`.
+    #[prop(default = false)]
+    slim: bool,
+    /// Whether the sidenav is collapsed to its slim version. This only has an effect if `slim` is `true`.
+    #[prop(into, default = false.into())]
+    slim_collapsed: MaybeSignal<bool>,
+    /// The width of the slim version of the sidenav in pixels.
+    #[prop(default = 77)]
+    slim_width: u32,
+    /// Whether to show a backdrop behind the sidenav in [SidenavMode::Over]. Clicking the backdrop hides the sidenav.
+    #[prop(default = true)]
+    backdrop: bool,
+    /// Whether pressing the escape key hides the sidenav. This has no effect in [SidenavMode::Side].
+    #[prop(default = true)]
+    close_on_esc: bool,
+    /// If the window is narrower than this many pixels, the sidenav switches to [SidenavMode::Over] and is hidden
+    /// until it is shown again.
+    #[prop(optional)]
+    hide_below: Option<u32>,
+    /// This signal is read to decide whether the sidenav is showing.
+    /// Whenever this signal changes, the sidenav is shown or hidden.
+    /// But note that the sidenav can also be hidden by the user, e.g. by clicking the backdrop, and if the
+    /// `on_hide` callback doesn't update the `open` signal, the sidenav can get out of sync with the signal.
+    /// If not set, the sidenav is initially showing.
+    #[prop(into, optional)]
+    open: Option<Signal<bool>>,
+    /// A handle to show or hide the sidenav from code. See [SidenavHandle].
+    #[prop(optional)]
+    handle: Option<SidenavHandle>,
+    /// The path of the current page. The [SidenavItem] whose `href` matches it is highlighted.
+    /// If not set and the `router` feature is enabled, the path of the current route of the surrounding
+    /// `leptos_router` router is used. Otherwise, Tailwind Elements guesses the active item from the URL
+    /// when the sidenav is created.
+    #[prop(into, optional)]
+    active_path: Option<Signal<String>>,
+    /// Callback that is called when the sidenav starts showing.
+    #[prop(into, optional)]
+    on_show: Option<Callback<()>>,
+    /// Callback that is called when the sidenav starts hiding.
+    #[prop(into, optional)]
+    on_hide: Option<Callback<()>>,
+    /// The content of the sidenav, usually [SidenavItem]s and [SidenavGroup]s.
+    children: Children,
+) -> impl IntoView {
+    let handle = handle.unwrap_or_default();
+    #[cfg(feature = "router")]
+    let active_path = active_path.or_else(|| {
+        // `use_location` panics outside of a router
+        use_context::<leptos_router::RouterContext>()
+            .map(|_| leptos_router::use_location().pathname.into())
+    });
+    provide_context(SidenavContext { active_path });
+
+    let (resized, set_resized) = create_signal(());
+    if hide_below.is_some() {
+        let resize_handle = window_event_listener(ev::resize, move |_| set_resized(()));
+        on_cleanup(move || resize_handle.remove());
+    }
+    // This is only read in effects and event handlers, so it doesn't access the window during server side rendering.
+    let narrow = create_memo(move |_| {
+        resized();
+        hide_below.is_some_and(|hide_below| {
+            window()
+                .inner_width()
+                .ok()
+                .and_then(|width| width.as_f64())
+                .is_some_and(|width| width < f64::from(hide_below))
+        })
+    });
+    let effective_mode = move || {
+        if narrow() {
+            SidenavMode::Over
+        } else {
+            mode()
+        }
+    };
+
+    // Tailwind Elements only closes the sidenav on escape if it was opened by a toggle button, so we handle it ourselves.
+    if close_on_esc {
+        let keydown_handle = window_event_listener(ev::keydown, move |ev| {
+            if ev.key() == "Escape"
+                && effective_mode() != SidenavMode::Side
+                && handle.showing().get_untracked()
+            {
+                handle.hide();
+            }
+        });
+        on_cleanup(move || keydown_handle.remove());
+    }
+
     // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
     let element_ref: NodeRef<Nav> = create_node_ref();
     create_effect(move |_| {
         if let Some(element) = element_ref() {
+            let hidden = narrow() || open.is_some_and(|open| !open.get_untracked());
             let options = JsSidenavOptions {
-                hidden: false,
-                mode: "side".to_string(),
-                content: format!("#{}", content_id),
-                position: "fixed".to_string(),
+                hidden,
+                mode: effective_mode().as_str(),
+                content: content_id.as_ref().map(|id| format!("#{id}")),
+                position: position.as_str(),
+                right: placement == SidenavPlacement::Right,
+                width,
+                slim,
+                slim_collapsed: slim_collapsed.get_untracked(),
+                slim_width,
+                backdrop,
+                // Without this, Tailwind Elements would add the (possibly empty) id of the sidenav as a class to the backdrop
+                backdrop_class: format!(
+                    "opacity-50 transition-all duration-300 ease-in-out {} top-0 left-0 z-50 bg-black/10 dark:bg-black-60 w-full h-full",
+                    position.as_str(),
+                ),
+                close_on_esc: false,
             };
             let jssidenav =
                 JsSidenav::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
-            on_cleanup(move || jssidenav.dispose());
+            handle.sidenav_impl.with_value(|s| {
+                *s.jssidenav.borrow_mut() = Some(jssidenav);
+                (s.set_showing)(!hidden);
+            });
+
+            let on_show_closure: Closure<dyn FnMut()> = {
+                let on_show = on_show.clone();
+                Closure::new(move || {
+                    handle.sidenav_impl.with_value(|s| (s.set_showing)(true));
+                    if let Some(on_show) = &on_show {
+                        on_show(());
+                    }
+                })
+            };
+            let on_hide_closure: Closure<dyn FnMut()> = {
+                let on_hide = on_hide.clone();
+                Closure::new(move || {
+                    handle.sidenav_impl.with_value(|s| (s.set_showing)(false));
+                    if let Some(on_hide) = &on_hide {
+                        on_hide(());
+                    }
+                })
+            };
+            let show_listener =
+                te_sidenav_add_event_listener(&element, "show.te.sidenav", &on_show_closure);
+            let hide_listener =
+                te_sidenav_add_event_listener(&element, "hide.te.sidenav", &on_hide_closure);
+
+            // The initial state was already passed in the options, so we only react to changes.
+            if let Some(open) = open {
+                create_effect(move |prev: Option<()>| {
+                    let open = open();
+                    if prev.is_some() && open != handle.showing().get_untracked() {
+                        if open {
+                            handle.show();
+                        } else {
+                            handle.hide();
+                        }
+                    }
+                });
+            }
+            create_effect(move |prev: Option<()>| {
+                let slim_collapsed = slim_collapsed();
+                if prev.is_some() {
+                    handle
+                        .sidenav_impl
+                        .with_value(|s| s.set_slim_collapsed(slim_collapsed));
+                }
+            });
+
+            // We need to copy the Rc and capture it because the stored value may already be un-stored in `on_cleanup`.
+            let sidenav_impl = handle.sidenav_impl.with_value(Rc::clone);
+            on_cleanup(move || {
+                te_sidenav_remove_event_listener(&element, "show.te.sidenav", &show_listener);
+                te_sidenav_remove_event_listener(&element, "hide.te.sidenav", &hide_listener);
+                let jssidenav = sidenav_impl.jssidenav.borrow_mut().take();
+                if let Some(jssidenav) = jssidenav {
+                    jssidenav.dispose();
+                }
+                std::mem::drop(on_show_closure);
+                std::mem::drop(on_hide_closure);
+            });
         }
     });
 
+    let initially_hidden = open.is_some_and(|open| !open.get_untracked());
+    let class = format!(
+        "{} {} top-0 z-[1035] h-screen w-60 overflow-hidden bg-white shadow-[0_4px_12px_0_rgba(0,0,0,0.07),_0_2px_4px_rgba(0,0,0,0.05)] data-[te-sidenav-hidden='false']:translate-x-0 dark:bg-zinc-800",
+        position.as_str(),
+        placement.class(),
+    );
+
     view! {
         <nav
             ref=element_ref
-            class="fixed left-0 top-0 z-[1035] h-screen w-60 -translate-x-full overflow-hidden bg-white shadow-[0_4px_12px_0_rgba(0,0,0,0.07),_0_2px_4px_rgba(0,0,0,0.05)] data-[te-sidenav-hidden='false']:translate-x-0 dark:bg-zinc-800"
+            class=class
             // TODO Not sure why this additional "data-te-sidenav-hidden" is needed, the JavaScript should initialize it correctly.
-            data-te-sidenav-hidden="false"
+            data-te-sidenav-hidden=initially_hidden.to_string()
             >
             <ul class="relative m-0 list-none px-[0.2rem]" data-te-sidenav-menu-ref>
                 {children()}
@@ -37,20 +404,211 @@ pub fn Sidenav(#[prop(into)] content_id: String, children: Children) -> impl Int
     }
 }
 
+#[derive(Clone, Copy)]
+struct SidenavContext {
+    active_path: Option<Signal<String>>,
+}
+
+// Provided by a [SidenavGroup] so that the items inside of it know that they're nested.
+#[derive(Clone, Copy)]
+struct SidenavGroupContext;
+
+const LINK_CLASS: &str = "flex cursor-pointer items-center truncate rounded-[5px] text-gray-600 outline-none transition duration-300 ease-linear hover:bg-slate-50 hover:text-inherit hover:outline-none focus:bg-slate-50 focus:text-inherit focus:outline-none active:bg-slate-50 active:text-inherit active:outline-none data-[te-sidenav-state-active]:text-inherit data-[te-sidenav-state-focus]:outline-none motion-reduce:transition-none dark:text-gray-300 dark:hover:bg-white/10 dark:focus:bg-white/10 dark:active:bg-white/10";
+
+fn link_class(nested: bool) -> String {
+    if nested {
+        format!("{LINK_CLASS} h-6 py-4 pl-[3.4rem] pr-6 text-[0.78rem]")
+    } else {
+        format!("{LINK_CLASS} h-12 px-6 py-4 text-[0.875rem]")
+    }
+}
+
+/// An icon shown in front of the text of a [SidenavItem] or [SidenavGroup]. Pass it with `<SidenavIcon slot>`.
+#[slot]
+pub struct SidenavIcon {
+    /// The icon, usually an `<svg>`
+    children: Children,
+}
+
+/// A badge shown at the end of a [SidenavItem] or [SidenavGroup], e.g. a number of unread messages.
+/// Pass it with `<SidenavBadge slot>`.
+#[slot]
+pub struct SidenavBadge {
+    /// The content of the badge
+    children: Children,
+}
+
+/// An item of a [Sidenav], i.e. a link to another page or to a section of the current page.
+///
+/// If the sidenav has an `active_path`, the item is highlighted while its `href` matches that path.
 #[component]
-pub fn SidenavItem(#[prop(into)] href: MaybeSignal<String>, children: Children) -> impl IntoView {
+pub fn SidenavItem(
+    /// The target of the link.
+    #[prop(into)]
+    href: MaybeSignal<String>,
+    /// An icon shown in front of the text. See [SidenavIcon].
+    #[prop(optional)]
+    sidenav_icon: Option<SidenavIcon>,
+    /// A badge shown at the end of the item. See [SidenavBadge].
+    #[prop(optional)]
+    sidenav_badge: Option<SidenavBadge>,
+    /// The content of the item, usually its text.
+    children: Children,
+) -> impl IntoView {
+    let nested = use_context::<SidenavGroupContext>().is_some();
+    let active_path = use_context::<SidenavContext>().and_then(|context| context.active_path);
+    let href = Signal::derive(move || href.get());
+    let active = move || {
+        active_path.is_some_and(|active_path| {
+            active_path.with(|active_path| href.with(|href| is_same_path(href, active_path)))
+        })
+    };
+
     view! {
         <li class="relative">
             <a
-                class="flex h-12 cursor-pointer items-center truncate rounded-[5px] px-6 py-4 text-[0.875rem] text-gray-600 outline-none transition duration-300 ease-linear hover:bg-slate-50 hover:text-inherit hover:outline-none focus:bg-slate-50 focus:text-inherit focus:outline-none active:bg-slate-50 active:text-inherit active:outline-none data-[te-sidenav-state-active]:text-inherit data-[te-sidenav-state-focus]:outline-none motion-reduce:transition-none dark:text-gray-300 dark:hover:bg-white/10 dark:focus:bg-white/10 dark:active:bg-white/10"
+                class=link_class(nested)
                 href=href
-                data-te-sidenav-link-ref>
-                {children()}
+                data-te-sidenav-link-ref
+                data-te-sidenav-state-active=active
+                // Allows a surrounding Scrollspy to highlight this item
+                data-te-nav-link-ref>
+                {sidenav_icon.map(|icon| view! { <SidenavIconWrapper>{(icon.children)()}</SidenavIconWrapper> })}
+                <span data-te-sidenav-slim="false">{children()}</span>
+                {sidenav_badge.map(|badge| view! { <SidenavBadgeWrapper class="ml-auto">{(badge.children)()}</SidenavBadgeWrapper> })}
             </a>
         </li>
     }
 }
 
+/// A collapsible group of items in a [Sidenav]. Clicking its title shows or hides the nested items.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Sidenav content_id="content">
+///         <SidenavItem href="/">"Home"</SidenavItem>
+///         <SidenavGroup title="Settings">
+///             <SidenavItem href="/settings/profile">"Profile"</SidenavItem>
+///             <SidenavItem href="/settings/security">"Security"</SidenavItem>
+///         </SidenavGroup>
+///     </Sidenav>
+/// }
+/// ```
+#[component]
+pub fn SidenavGroup(
+    /// The title of the group.
+    #[prop(into)]
+    title: MaybeSignal<String>,
+    /// An icon shown in front of the title. See [SidenavIcon].
+    #[prop(optional)]
+    sidenav_icon: Option<SidenavIcon>,
+    /// A badge shown at the end of the title. See [SidenavBadge].
+    #[prop(optional)]
+    sidenav_badge: Option<SidenavBadge>,
+    /// Whether the group is initially expanded.
+    #[prop(default = false)]
+    expanded: bool,
+    /// The nested items, usually [SidenavItem]s.
+    children: Children,
+) -> impl IntoView {
+    let nested = use_context::<SidenavGroupContext>().is_some();
+    provide_context(SidenavGroupContext);
+
+    // Tailwind Elements finds the collapsible list and its toggle link when the sidenav is created
+    // and sets up the collapse, including the `href` of the toggle link.
+    view! {
+        <li class="relative">
+            <a class=link_class(nested) data-te-sidenav-link-ref>
+                {sidenav_icon.map(|icon| view! { <SidenavIconWrapper>{(icon.children)()}</SidenavIconWrapper> })}
+                <span data-te-sidenav-slim="false">{title}</span>
+                {sidenav_badge.map(|badge| view! { <SidenavBadgeWrapper class="ml-auto mr-6">{(badge.children)()}</SidenavBadgeWrapper> })}
+                <span
+                    class="absolute right-0 ml-auto mr-[0.8rem] transition-transform duration-300 ease-linear motion-reduce:transition-none [&>svg]:text-gray-600 dark:[&>svg]:text-gray-300"
+                    data-te-sidenav-rotate-icon-ref
+                    data-te-sidenav-slim="false"
+                >
+                    <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 20" fill="currentColor" class="h-5 w-5">
+                        <path fill-rule="evenodd" d="M5.23 7.21a.75.75 0 011.06.02L10 11.168l3.71-3.938a.75.75 0 111.08 1.04l-4.25 4.5a.75.75 0 01-1.08 0l-4.25-4.5a.75.75 0 01.02-1.06z" clip-rule="evenodd" />
+                    </svg>
+                </span>
+            </a>
+            <ul
+                class=if expanded { "!visible relative m-0 list-none p-0 data-[te-collapse-show]:block" } else { "!visible relative m-0 hidden list-none p-0 data-[te-collapse-show]:block" }
+                data-te-sidenav-collapse-ref
+                data-te-collapse-show=expanded
+                data-te-sidenav-state-show=expanded
+            >
+                {children()}
+            </ul>
+        </li>
+    }
+}
+
+/// A header in a [Sidenav], e.g. to give a title to the items following it.
+#[component]
+pub fn SidenavHeader(
+    /// The text of the header.
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <li class="relative pt-4">
+            <span
+                class="px-6 py-4 text-[0.6rem] font-bold uppercase text-gray-600 dark:text-gray-400"
+                data-te-sidenav-slim="false"
+            >
+                {children()}
+            </span>
+        </li>
+    }
+}
+
+/// A horizontal line in a [Sidenav] to separate groups of items.
+#[component]
+pub fn SidenavDivider() -> impl IntoView {
+    view! {
+        <li class="relative">
+            <hr class="my-2 border-gray-300 dark:border-gray-600" />
+        </li>
+    }
+}
+
+#[component]
+fn SidenavIconWrapper(children: Children) -> impl IntoView {
+    view! {
+        <span class="mr-4 [&>svg]:h-4 [&>svg]:w-4 [&>svg]:text-gray-400 dark:[&>svg]:text-gray-300">
+            {children()}
+        </span>
+    }
+}
+
+#[component]
+fn SidenavBadgeWrapper(class: &'static str, children: Children) -> impl IntoView {
+    view! {
+        <span class=class data-te-sidenav-slim="false">
+            <span class="inline-block whitespace-nowrap rounded-[0.27rem] bg-primary-100 px-[0.65em] pb-[0.25em] pt-[0.35em] text-center align-baseline text-[0.75em] font-bold leading-none text-primary-700">
+                {children()}
+            </span>
+        </span>
+    }
+}
+
+// Compares the path of a link with the path of the current page, ignoring query strings, fragments and trailing slashes.
+// Links to sections of the current page, e.g. `#installation`, never match.
+fn is_same_path(href: &str, path: &str) -> bool {
+    fn normalize(path: &str) -> &str {
+        let path = path.split(['?', '#']).next().unwrap_or("");
+        let path = path.trim_end_matches('/');
+        if path.is_empty() {
+            "/"
+        } else {
+            path
+        }
+    }
+    !href.starts_with('#') && normalize(href) == normalize(path)
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = te, js_name = Sidenav)]
@@ -59,22 +617,93 @@ extern "C" {
     #[wasm_bindgen(constructor, js_namespace = te, js_class = Sidenav, final)]
     fn new(e: &HtmlElement, options: JsValue) -> JsSidenav;
 
+    #[wasm_bindgen(method, js_namespace = te, js_class = Sidenav, final)]
+    fn show(this: &JsSidenav);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Sidenav, final)]
+    fn hide(this: &JsSidenav);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Sidenav, final)]
+    fn toggle(this: &JsSidenav);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Sidenav, js_name = toggleSlim, final)]
+    fn toggle_slim(this: &JsSidenav);
+
+    // Tailwind Elements doesn't offer a public getter for this, and the sidenav can also be collapsed by swiping
+    #[wasm_bindgen(method, getter, js_namespace = te, js_class = Sidenav, js_name = _slimCollapsed, final)]
+    fn slim_collapsed(this: &JsSidenav) -> bool;
+
     #[wasm_bindgen(method, js_namespace = te, js_class = Sidenav, final)]
     fn dispose(this: &JsSidenav);
 }
 
-#[derive(Serialize, Deserialize)]
+// The sidenav element outlives the Tailwind Elements object when it is re-created with new options,
+// so we need to remove the listeners again.
+#[wasm_bindgen(inline_js = "
+    export function te_sidenav_add_event_listener(sidenav_html_elem, event_name, callback) {
+        const listener = (event) => {
+            callback();
+        };
+        sidenav_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_sidenav_remove_event_listener(sidenav_html_elem, event_name, listener) {
+        sidenav_html_elem.removeEventListener(event_name, listener);
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_sidenav_add_event_listener(
+        sidenav: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_sidenav_remove_event_listener(
+        sidenav: &web_sys::HtmlElement,
+        event_name: &str,
+        listener: &JsValue,
+    );
+}
+
+#[derive(Serialize)]
 struct JsSidenavOptions {
     #[serde(rename = "sidenavHidden")]
     hidden: bool,
 
     #[serde(rename = "sidenavMode")]
-    mode: String,
+    mode: &'static str,
 
     #[serde(rename = "sidenavContent")]
-    content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
 
     #[serde(rename = "sidenavPosition")]
-    position: String,
+    position: &'static str,
+
+    #[serde(rename = "sidenavRight")]
+    right: bool,
+
+    #[serde(rename = "sidenavWidth")]
+    width: u32,
+
+    #[serde(rename = "sidenavSlim")]
+    slim: bool,
+
+    #[serde(rename = "sidenavSlimCollapsed")]
+    slim_collapsed: bool,
+
+    #[serde(rename = "sidenavSlimWidth")]
+    slim_width: u32,
+
+    #[serde(rename = "sidenavBackdrop")]
+    backdrop: bool,
+
+    #[serde(rename = "sidenavBackdropClass")]
+    backdrop_class: String,
+
+    #[serde(rename = "sidenavCloseOnEsc")]
+    close_on_esc: bool,
     // TODO There are more options, see https://tailwind-elements.com/docs/standard/navigation/sidenav/#docsTabsAPI
 }
@@ -0,0 +1,249 @@
+use leptos::{html::A, *};
+use std::rc::Rc;
+use wasm_bindgen::prelude::wasm_bindgen;
+use web_sys::HtmlElement;
+
+/// How the tabs of a [Tabs] component are laid out.
+///
+/// See [Tailwind Elements: Tabs](https://tailwind-elements.com/docs/standard/navigation/tabs/)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TabsLayout {
+    /// Tabs are shown in a row above the panels
+    #[default]
+    Horizontal,
+    /// Tabs are shown in a column to the left of the panels
+    Vertical,
+    /// Tabs are shown as pills in a row above the panels
+    Pills,
+    /// Tabs are shown in a row above the panels and fill its whole width
+    Justified,
+}
+
+impl TabsLayout {
+    const fn wrapper_class(self) -> &'static str {
+        match self {
+            Self::Vertical => "flex items-start",
+            Self::Horizontal | Self::Pills | Self::Justified => "",
+        }
+    }
+
+    const fn list_class(self) -> &'static str {
+        match self {
+            Self::Horizontal | Self::Justified => {
+                "mb-5 flex list-none flex-row flex-wrap border-b-0 pl-0"
+            }
+            Self::Vertical => "mr-4 flex list-none flex-col flex-wrap pl-0",
+            Self::Pills => "mb-5 flex list-none flex-col flex-wrap pl-0 md:flex-row",
+        }
+    }
+
+    const fn item_class(self) -> &'static str {
+        match self {
+            Self::Horizontal | Self::Vertical => "",
+            Self::Pills => "text-center",
+            Self::Justified => "flex-grow basis-0 text-center",
+        }
+    }
+
+    const fn link_class(self) -> &'static str {
+        match self {
+            Self::Horizontal | Self::Vertical | Self::Justified => "my-2 block border-x-0 border-b-2 border-t-0 border-transparent px-7 pb-3.5 pt-4 text-xs font-medium uppercase leading-tight text-neutral-500 hover:isolate hover:border-transparent hover:bg-neutral-100 focus:isolate focus:border-transparent data-[te-nav-active]:border-primary data-[te-nav-active]:text-primary dark:text-neutral-400 dark:hover:bg-transparent dark:data-[te-nav-active]:border-primary-400 dark:data-[te-nav-active]:text-primary-400",
+            Self::Pills => "my-2 block rounded bg-neutral-100 px-7 pb-3.5 pt-4 text-xs font-medium uppercase leading-tight text-neutral-500 data-[te-nav-active]:!bg-primary-100 data-[te-nav-active]:text-primary-700 dark:bg-neutral-700 dark:text-white dark:data-[te-nav-active]:text-primary-700 md:mr-4",
+        }
+    }
+}
+
+/// A tab of a [Tabs] component. Pass it to the tabs with `<Tab slot key=...>`.
+#[slot]
+pub struct Tab<K> {
+    /// The key identifying this tab. The [TabPanel] with the same key is shown when this tab is active.
+    key: K,
+    /// The title of the tab
+    children: Children,
+}
+
+/// A panel of a [Tabs] component. Pass it to the tabs with `<TabPanel slot key=...>`.
+#[slot]
+pub struct TabPanel<K> {
+    /// The key identifying this panel. It is shown when the [Tab] with the same key is active.
+    key: K,
+    /// The content of the panel
+    children: ChildrenFn,
+}
+
+/// A Tabs component, i.e. a row of tabs that each show a different panel.
+///
+/// Tabs and panels are identified by a key of type `K`, for example an enum.
+///
+/// Example
+/// -------
+/// ```ignore
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Page { Home, Profile }
+///
+/// let (active, set_active) = create_signal(Page::Home);
+/// view! {
+///     <Tabs id="profile-tabs" active=active on_change=set_active>
+///         <Tab slot key=Page::Home>"Home"</Tab>
+///         <Tab slot key=Page::Profile>"Profile"</Tab>
+///         <TabPanel slot key=Page::Home>"Home content"</TabPanel>
+///         <TabPanel slot key=Page::Profile>"Profile content"</TabPanel>
+///     </Tabs>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Tabs](https://tailwind-elements.com/docs/standard/navigation/tabs/)
+#[component]
+pub fn Tabs<K: Clone + PartialEq + 'static, OnChangeFn: Fn(K) + 'static>(
+    /// This signal is read to decide which tab is active.
+    /// Whenever this signal changes, the active tab is updated.
+    /// But note that clicking a tab doesn't activate it by itself, it only calls `on_change`,
+    /// which is expected to update the `active` signal.
+    #[prop(into)]
+    active: Signal<K>,
+    /// Callback that is called with the key of a tab when the user clicks it.
+    on_change: OnChangeFn,
+    /// How the tabs are laid out. See [TabsLayout].
+    #[prop(default = TabsLayout::Horizontal)]
+    layout: TabsLayout,
+    /// If `true`, the content of a panel is only rendered once its tab is activated for the first time.
+    #[prop(default = false)]
+    lazy: bool,
+    /// Used as a prefix for the ids of the tabs and panels.
+    // TODO Auto-assign id
+    #[prop(into)]
+    id: String,
+    /// The tabs. See [Tab].
+    tab: Vec<Tab<K>>,
+    /// The panels. See [TabPanel].
+    tab_panel: Vec<TabPanel<K>>,
+) -> impl IntoView {
+    let on_change = store_value(on_change);
+
+    let tab_id = {
+        let id = id.clone();
+        move |index: usize| format!("{id}-tab-{index}")
+    };
+    let panel_id = move |index: usize| format!("{id}-panel-{index}");
+    let tab_ids: Vec<_> = (0..tab.len()).map(tab_id).collect();
+    let panel_ids: Vec<_> = (0..tab_panel.len()).map(panel_id).collect();
+    let tab_id_for = |key: &K| {
+        tab.iter()
+            .position(|tab| &tab.key == key)
+            .map(|index| tab_ids[index].clone())
+    };
+    let panel_id_for = |key: &K| {
+        tab_panel
+            .iter()
+            .position(|panel| &panel.key == key)
+            .map(|index| panel_ids[index].clone())
+    };
+
+    let panels = tab_panel
+        .iter()
+        .zip(&panel_ids)
+        .map(|(panel, id)| {
+            let key = panel.key.clone();
+            let children = panel.children.clone();
+            let is_active = create_memo(move |_| active.with(|active| active == &key));
+            let was_active = create_memo(move |was_active| {
+                was_active.copied().unwrap_or(false) || is_active()
+            });
+            // After the first render, Tailwind Elements takes care of showing and hiding the panels.
+            let initially_active = is_active.get_untracked();
+            let class = if initially_active {
+                "hidden opacity-100 transition-opacity duration-150 ease-linear data-[te-tab-active]:block"
+            } else {
+                "hidden opacity-0 transition-opacity duration-150 ease-linear data-[te-tab-active]:block"
+            };
+            view! {
+                <div
+                    class=class
+                    id=id
+                    role="tabpanel"
+                    aria-labelledby=tab_id_for(&panel.key)
+                    data-te-tab-active=initially_active.then_some("")
+                >
+                    <Show when=move || !lazy || was_active() fallback=|| ()>
+                        {children()}
+                    </Show>
+                </div>
+            }
+        })
+        .collect_view();
+
+    let tabs = tab
+        .into_iter()
+        .zip(tab_ids)
+        .map(|(tab, id)| {
+            let Tab { key, children } = tab;
+            let target = panel_id_for(&key);
+            let initially_active = active.with_untracked(|active| active == &key);
+
+            // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+            let link_ref: NodeRef<A> = create_node_ref();
+            let show_key = key.clone();
+            create_effect(move |_| {
+                if let Some(link) = link_ref() {
+                    let jstab = Rc::new(JsTab::new(&link));
+                    let key = show_key.clone();
+                    let jstab_clone = Rc::clone(&jstab);
+                    create_effect(move |_| {
+                        if active.with(|active| active == &key) {
+                            jstab_clone.show();
+                        }
+                    });
+                    on_cleanup(move || jstab.dispose());
+                }
+            });
+
+            view! {
+                <li role="presentation" class=layout.item_class()>
+                    <a
+                        ref=link_ref
+                        href=target.as_ref().map(|target| format!("#{target}"))
+                        class=layout.link_class()
+                        id=id
+                        data-te-target=target.as_ref().map(|target| format!("#{target}"))
+                        data-te-nav-active=initially_active.then_some("")
+                        role="tab"
+                        aria-controls=target
+                        aria-selected=initially_active.to_string()
+                        on:click=move |ev| {
+                            ev.prevent_default();
+                            on_change.with_value(|on_change| on_change(key.clone()));
+                        }
+                    >
+                        {children()}
+                    </a>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class=layout.wrapper_class()>
+            <ul class=layout.list_class() role="tablist" data-te-nav-ref>
+                {tabs}
+            </ul>
+            <div class="mb-6">
+                {panels}
+            </div>
+        </div>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Tab)]
+    type JsTab;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Tab, final)]
+    fn new(e: &HtmlElement) -> JsTab;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Tab, final)]
+    fn show(this: &JsTab);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Tab, final)]
+    fn dispose(this: &JsTab);
+}
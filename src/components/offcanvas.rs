@@ -0,0 +1,274 @@
+use leptos::{html::Div, *};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+use super::modal::CloseButton;
+
+/// The edge of the screen an [Offcanvas] slides in from.
+///
+/// See [Tailwind Elements: Offcanvas#Placement](https://tailwind-elements.com/docs/standard/components/offcanvas/#placement)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffcanvasPlacement {
+    /// Slide in from the left
+    #[default]
+    Start,
+    /// Slide in from the right
+    End,
+    /// Slide in from the top
+    Top,
+    /// Slide in from the bottom
+    Bottom,
+}
+
+impl OffcanvasPlacement {
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Start => "bottom-0 left-0 top-0 w-96 max-w-full -translate-x-full",
+            Self::End => "bottom-0 right-0 top-0 w-96 max-w-full translate-x-full",
+            Self::Top => "left-0 right-0 top-0 h-1/3 max-h-full -translate-y-full",
+            Self::Bottom => "bottom-0 left-0 right-0 h-1/3 max-h-full translate-y-full",
+        }
+    }
+}
+
+/// Options for an [Offcanvas].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffcanvasOptions {
+    /// The edge of the screen the offcanvas slides in from. See [OffcanvasPlacement].
+    pub placement: OffcanvasPlacement,
+
+    /// Whether to show a backdrop behind the offcanvas. Clicking the backdrop hides the offcanvas.
+    pub backdrop: bool,
+
+    /// Whether scrolling the page body is disabled while the offcanvas is showing.
+    pub scroll_lock: bool,
+
+    /// Whether pressing the escape key hides the offcanvas.
+    pub close_on_esc: bool,
+}
+
+impl Default for OffcanvasOptions {
+    fn default() -> Self {
+        Self {
+            placement: OffcanvasPlacement::Start,
+            backdrop: true,
+            scroll_lock: true,
+            close_on_esc: true,
+        }
+    }
+}
+
+/// An Offcanvas component, i.e. a drawer that slides in from an edge of the screen.
+///
+/// See [Tailwind Elements: Offcanvas](https://tailwind-elements.com/docs/standard/components/offcanvas/)
+#[derive(Clone, Copy)]
+pub struct Offcanvas {
+    offcanvas_impl: StoredValue<Rc<OffcanvasImpl>>,
+}
+
+impl Offcanvas {
+    /// Create a new offcanvas. This returns a handle to show or hide the offcanvas and a view that
+    /// needs to be added to the page. `content` is called with the handle and should return the
+    /// content of the offcanvas, usually an [OffcanvasHeader] and an [OffcanvasBody].
+    pub fn new<ContentFn, ContentView>(
+        options: OffcanvasOptions,
+        content: ContentFn,
+    ) -> (Self, impl IntoView)
+    where
+        ContentFn: Clone + Fn(Self) -> ContentView + 'static,
+        ContentView: IntoView,
+    {
+        let (showing, set_showing) = create_signal(false);
+        let offcanvas_impl = Rc::new(OffcanvasImpl {
+            jsoffcanvas: RefCell::new(None),
+            showing,
+            set_showing,
+        });
+        let offcanvas_impl = store_value(offcanvas_impl);
+        let offcanvas = Self { offcanvas_impl };
+        let view = view! {
+            <OffcanvasView offcanvas=offcanvas options=options content=content />
+        };
+        (offcanvas, view)
+    }
+
+    /// Slide the offcanvas in.
+    pub fn show(&self) {
+        self.offcanvas_impl.with_value(|o| o.show());
+    }
+
+    /// Slide the offcanvas out.
+    pub fn hide(&self) {
+        self.offcanvas_impl.with_value(|o| o.hide());
+    }
+
+    /// A signal that is `true` while the offcanvas is showing.
+    pub fn showing(&self) -> ReadSignal<bool> {
+        self.offcanvas_impl.with_value(|o| o.showing)
+    }
+}
+
+struct OffcanvasImpl {
+    jsoffcanvas: RefCell<Option<JsOffcanvas>>,
+    showing: ReadSignal<bool>,
+    set_showing: WriteSignal<bool>,
+}
+
+impl OffcanvasImpl {
+    pub fn show(&self) {
+        let jsoffcanvas = self.jsoffcanvas.borrow();
+        let Some(jsoffcanvas) = jsoffcanvas.as_ref() else {
+            panic!("Tried to show an offcanvas but its view is not added to the page");
+        };
+        jsoffcanvas.show();
+    }
+
+    pub fn hide(&self) {
+        let jsoffcanvas = self.jsoffcanvas.borrow();
+        let Some(jsoffcanvas) = jsoffcanvas.as_ref() else {
+            panic!("Tried to hide an offcanvas but its view is not added to the page");
+        };
+        jsoffcanvas.hide();
+    }
+}
+
+#[component]
+fn OffcanvasView<ContentFn, ContentView>(
+    offcanvas: Offcanvas,
+    options: OffcanvasOptions,
+    content: ContentFn,
+) -> impl IntoView
+where
+    ContentFn: Fn(Offcanvas) -> ContentView + 'static,
+    ContentView: IntoView,
+{
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let offcanvas_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = offcanvas_ref() {
+            let jsoptions = JsOffcanvasOptions {
+                backdrop: options.backdrop,
+                keyboard: options.close_on_esc,
+                scroll: !options.scroll_lock,
+            };
+            let jsoffcanvas =
+                JsOffcanvas::new(&element, serde_wasm_bindgen::to_value(&jsoptions).unwrap());
+            offcanvas
+                .offcanvas_impl
+                .with_value(|o| *o.jsoffcanvas.borrow_mut() = Some(jsoffcanvas));
+
+            let on_show: Closure<dyn FnMut()> = Closure::new(move || {
+                offcanvas
+                    .offcanvas_impl
+                    .with_value(|o| (o.set_showing)(true));
+            });
+
+            let on_hidden: Closure<dyn FnMut()> = Closure::new(move || {
+                offcanvas
+                    .offcanvas_impl
+                    .with_value(|o| (o.set_showing)(false));
+            });
+
+            te_offcanvas_add_event_listener(&element, "show.te.offcanvas", &on_show);
+            te_offcanvas_add_event_listener(&element, "hidden.te.offcanvas", &on_hidden);
+
+            // We need to copy the Rc and capture it because the stored value may already be un-stored in `on_cleanup`.
+            let offcanvas_impl = offcanvas.offcanvas_impl.with_value(Rc::clone);
+            on_cleanup(move || {
+                let jsoffcanvas = offcanvas_impl.jsoffcanvas.borrow_mut().take();
+                if let Some(jsoffcanvas) = jsoffcanvas {
+                    jsoffcanvas.dispose();
+                }
+                std::mem::drop(on_show);
+                std::mem::drop(on_hidden);
+            });
+        }
+    });
+    provide_context::<Offcanvas>(offcanvas);
+
+    let class = format!(
+        "invisible fixed z-[1045] flex flex-col border-none bg-white bg-clip-padding text-neutral-700 shadow-sm outline-none transition duration-300 ease-in-out dark:bg-neutral-800 dark:text-neutral-200 [&[data-te-offcanvas-show]]:transform-none {}",
+        options.placement.class()
+    );
+
+    view! {
+        <div
+            ref=offcanvas_ref
+            class=class
+            tabindex="-1"
+        >
+            {content(offcanvas)}
+        </div>
+    }
+}
+
+/// The header of an [Offcanvas], including a button to close it.
+#[component]
+pub fn OffcanvasHeader(children: Children) -> impl IntoView {
+    let offcanvas = use_context::<Offcanvas>().expect("Expected Offcanvas in context");
+    view! {
+        <div class="flex items-center justify-between p-4">
+            <h5 class="mb-0 font-semibold leading-normal">
+                {children()}
+            </h5>
+            <CloseButton on:click=move |_| offcanvas.hide() />
+        </div>
+    }
+}
+
+/// The scrollable body of an [Offcanvas].
+#[component]
+pub fn OffcanvasBody(children: Children) -> impl IntoView {
+    view! {
+        <div class="flex-grow overflow-y-auto p-4">
+            {children()}
+        </div>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Offcanvas)]
+    type JsOffcanvas;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Offcanvas, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsOffcanvas;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Offcanvas, final)]
+    fn show(this: &JsOffcanvas);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Offcanvas, final)]
+    fn hide(this: &JsOffcanvas);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Offcanvas, final)]
+    fn dispose(this: &JsOffcanvas);
+}
+
+#[wasm_bindgen(
+    inline_js = "export function te_offcanvas_add_event_listener(offcanvas_html_elem, event_name, callback) { offcanvas_html_elem.addEventListener(event_name, (event) => {
+        callback();
+    }); }"
+)]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_offcanvas_add_event_listener(
+        offcanvas: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsOffcanvasOptions {
+    backdrop: bool,
+
+    keyboard: bool,
+
+    scroll: bool,
+}
@@ -0,0 +1,237 @@
+use leptos::*;
+use std::future::Future;
+
+use super::{Popover, PopoverBody, Spinner, SpinnerSize, TooltipPlacement, TooltipTrigger};
+
+// Tailwind Elements' Popconfirm closes itself as soon as the OK button is clicked, so it can't wait for an async
+// confirmation and show a spinner meanwhile. We build it from a [Popover] instead and only reuse the Popconfirm styling.
+
+/// Whether a [Popconfirm] is shown as a popover next to its trigger or as a modal in the center of the screen.
+///
+/// See [Tailwind Elements: Popconfirm#Display mode](https://tailwind-elements.com/docs/standard/components/popconfirm/#display-mode)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PopconfirmMode {
+    /// Show the popconfirm as a popover attached to its trigger
+    #[default]
+    Inline,
+    /// Show the popconfirm in the center of the screen, on top of a backdrop
+    Modal,
+}
+
+/// An icon shown next to the message of a [Popconfirm]. Pass it to the popconfirm with `<PopconfirmIcon slot>`.
+#[slot]
+pub struct PopconfirmIcon {
+    /// The icon
+    children: ChildrenFn,
+}
+
+/// A Popconfirm component. It asks the user to confirm an action when its children are clicked.
+///
+/// `on_confirm` returns a future. While it runs, the OK button shows a [Spinner]. If it resolves to `Ok`,
+/// the popconfirm closes. If it resolves to `Err`, the popconfirm stays open, so the user can retry or cancel,
+/// and the error is passed to `on_error`.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Popconfirm
+///         message="Do you really want to delete this item?"
+///         ok_text="Delete"
+///         on_confirm=move || async move { delete_item(id).await }
+///         on_error=move |err: ServerFnError| log::error!("Failed to delete item: {err}")
+///     >
+///         <Button style=ButtonStyle::Danger>"Delete"</Button>
+///     </Popconfirm>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Popconfirm](https://tailwind-elements.com/docs/standard/components/popconfirm/)
+#[component]
+pub fn Popconfirm<
+    E: 'static,
+    ConfirmFut: Future<Output = Result<(), E>> + 'static,
+    ConfirmFn: Fn() -> ConfirmFut + 'static,
+>(
+    /// The question shown to the user.
+    #[prop(into, default = "Are you sure?".into())]
+    message: MaybeSignal<String>,
+    /// The text of the OK button.
+    #[prop(into, default = "OK".into())]
+    ok_text: MaybeSignal<String>,
+    /// The text of the cancel button.
+    #[prop(into, default = "Cancel".into())]
+    cancel_text: MaybeSignal<String>,
+    /// An icon shown next to the message. See [PopconfirmIcon].
+    #[prop(optional)]
+    popconfirm_icon: Option<PopconfirmIcon>,
+    /// Whether the popconfirm is shown as a popover or as a modal. See [PopconfirmMode].
+    #[prop(default = PopconfirmMode::Inline)]
+    mode: PopconfirmMode,
+    /// Where the popconfirm is shown relative to the children in [PopconfirmMode::Inline]. See [TooltipPlacement].
+    #[prop(into, default = TooltipPlacement::Bottom.into())]
+    placement: MaybeSignal<TooltipPlacement>,
+    /// Callback that is called when the user clicks the OK button.
+    on_confirm: ConfirmFn,
+    /// Callback that is called with the error if the future returned by `on_confirm` resolves to `Err`.
+    #[prop(into, optional)]
+    on_error: Option<Callback<E>>,
+    /// Callback that is called when the user cancels, i.e. clicks the cancel button, presses escape or clicks outside of the popconfirm.
+    #[prop(into, optional)]
+    on_cancel: Option<Callback<()>>,
+    /// The element(s) that open the popconfirm when clicked.
+    children: Children,
+) -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    let (pending, set_pending) = create_signal(false);
+
+    let on_confirm = store_value(on_confirm);
+    let on_error = store_value(on_error);
+    let on_cancel = store_value(on_cancel);
+    let confirm = move || {
+        if pending.get_untracked() {
+            return;
+        }
+        set_pending(true);
+        let future = on_confirm.with_value(|on_confirm| on_confirm());
+        spawn_local(async move {
+            let result = future.await;
+            // The popconfirm may have been removed from the page while the future was running
+            let _ = set_pending.try_set(false);
+            match result {
+                Ok(()) => {
+                    let _ = set_open.try_set(false);
+                }
+                Err(err) => {
+                    if let Some(Some(on_error)) = on_error.try_get_value() {
+                        on_error(err);
+                    }
+                }
+            }
+        });
+    };
+    let cancel = move || {
+        // Closing it while the confirmation is running would hide its outcome from the user
+        if !pending.get_untracked() && open.get_untracked() {
+            set_open(false);
+            on_cancel.with_value(|on_cancel| {
+                if let Some(on_cancel) = on_cancel {
+                    on_cancel(());
+                }
+            });
+        }
+    };
+    // The popover also hides itself when the user clicks outside of it, but not while the confirmation is running
+    let on_hidden = move |()| {
+        if open.get_untracked() {
+            set_open(false);
+            on_cancel.with_value(|on_cancel| {
+                if let Some(on_cancel) = on_cancel {
+                    on_cancel(());
+                }
+            });
+        }
+    };
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.key() == "Escape" {
+            cancel();
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    let content = move || {
+        view! {
+            <PopconfirmContent
+                message=message.clone()
+                ok_text=ok_text.clone()
+                cancel_text=cancel_text.clone()
+                icon=popconfirm_icon.as_ref().map(|icon| icon.children.clone())
+                pending=pending
+                confirm=Callback::new(move |()| confirm())
+                cancel=Callback::new(move |()| cancel())
+            />
+        }
+    };
+
+    match mode {
+        PopconfirmMode::Inline => view! {
+            <Popover
+                placement=placement
+                trigger=TooltipTrigger::Manual
+                open=Signal::from(open)
+                dismiss_on_outside_click=Signal::derive(move || !pending())
+                on_hidden=Callback::new(on_hidden)
+            >
+                <PopoverBody slot>{content()}</PopoverBody>
+                <span on:click=move |_| set_open(true)>{children()}</span>
+            </Popover>
+        }
+        .into_view(),
+        PopconfirmMode::Modal => view! {
+            <span class="inline-block" on:click=move |_| set_open(true)>{children()}</span>
+            <Show when=open fallback=|| ()>
+                <div
+                    class="fixed left-0 top-0 z-[1070] flex h-full w-full items-center justify-center bg-[#00000066]"
+                    on:click=move |_| cancel()
+                >
+                    <div
+                        class="absolute z-[1080] w-[300px] rounded-[0.5rem] shadow-sm"
+                        on:click=|ev| ev.stop_propagation()
+                    >
+                        <div class="rounded-[0.5rem] bg-white p-[1rem] dark:bg-neutral-700">
+                            {content()}
+                        </div>
+                    </div>
+                </div>
+            </Show>
+        }
+        .into_view(),
+    }
+}
+
+#[component]
+fn PopconfirmContent(
+    message: MaybeSignal<String>,
+    ok_text: MaybeSignal<String>,
+    cancel_text: MaybeSignal<String>,
+    icon: Option<ChildrenFn>,
+    pending: ReadSignal<bool>,
+    confirm: Callback<()>,
+    cancel: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <p class="mb-3 flex">
+            {icon.map(|icon| view! {
+                <span class="pr-2">{icon()}</span>
+            })}
+            <span class="text-neutral-600 dark:text-white">{message}</span>
+        </p>
+        <div class="flex justify-end space-x-2">
+            <button
+                type="button"
+                class="inline-block rounded bg-primary-100 px-4 pb-[5px] pt-[6px] text-xs font-medium uppercase leading-normal text-primary-700 transition duration-150 ease-in-out hover:bg-primary-accent-100 focus:bg-primary-accent-100 focus:outline-none focus:ring-0 active:bg-primary-accent-200 disabled:pointer-events-none disabled:opacity-60"
+                aria-label="Cancel"
+                disabled=pending
+                on:click=move |_| cancel(())
+            >
+                {cancel_text}
+            </button>
+            <button
+                type="button"
+                class="inline-flex items-center rounded bg-primary px-4 pb-[5px] pt-[6px] text-xs font-medium uppercase leading-normal text-white shadow-[0_4px_9px_-4px_#3b71ca] transition duration-150 ease-in-out hover:bg-primary-600 hover:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] focus:bg-primary-600 focus:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] focus:outline-none focus:ring-0 active:bg-primary-700 active:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.3),0_4px_18px_0_rgba(59,113,202,0.2)] disabled:pointer-events-none dark:shadow-[0_4px_9px_-4px_rgba(59,113,202,0.5)] dark:hover:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)] dark:focus:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)] dark:active:shadow-[0_8px_9px_-4px_rgba(59,113,202,0.2),0_4px_18px_0_rgba(59,113,202,0.1)]"
+                aria-label="Confirm"
+                aria-busy=move || pending().to_string()
+                disabled=pending
+                on:click=move |_| confirm(())
+            >
+                <Show when=pending fallback=|| ()>
+                    <span class="mr-2 inline-flex">
+                        <Spinner size=SpinnerSize::Small />
+                    </span>
+                </Show>
+                {ok_text}
+            </button>
+        </div>
+    }
+}
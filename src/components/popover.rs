@@ -0,0 +1,241 @@
+use leptos::{
+    html::{Div, Span},
+    *,
+};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+    JsCast,
+};
+use web_sys::HtmlElement;
+
+use super::{TooltipPlacement, TooltipTrigger};
+
+/// The header of a [Popover]. Pass it to the popover with `<PopoverHeader slot>`.
+#[slot]
+pub struct PopoverHeader {
+    /// Content shown in the header of the popover
+    children: Children,
+}
+
+/// The body of a [Popover]. Pass it to the popover with `<PopoverBody slot>`.
+#[slot]
+pub struct PopoverBody {
+    /// Content shown in the body of the popover
+    children: Children,
+}
+
+/// A Popover component. It attaches a popover to its children.
+///
+/// Example
+/// -------
+/// ```ignore
+/// view! {
+///     <Popover>
+///         <PopoverHeader slot>"Need help?"</PopoverHeader>
+///         <PopoverBody slot>"See the " <a href="/docs">"documentation"</a></PopoverBody>
+///         <Button style=ButtonStyle::Primary>"Help"</Button>
+///     </Popover>
+/// }
+/// ```
+///
+/// See [Tailwind Elements: Popover](https://tailwind-elements.com/docs/standard/components/popover/)
+#[component]
+pub fn Popover(
+    /// The header of the popover. See [PopoverHeader].
+    #[prop(optional)]
+    popover_header: Option<PopoverHeader>,
+    /// The body of the popover. See [PopoverBody].
+    popover_body: PopoverBody,
+    /// Where the popover is shown relative to the children. See [TooltipPlacement].
+    #[prop(into, default = TooltipPlacement::Right.into())]
+    placement: MaybeSignal<TooltipPlacement>,
+    /// What user interaction shows the popover. See [TooltipTrigger].
+    #[prop(into, default = TooltipTrigger::Click.into())]
+    trigger: MaybeSignal<TooltipTrigger>,
+    /// Whether the popover is hidden when the user clicks anywhere outside of it.
+    #[prop(into, default = false.into())]
+    dismiss_on_outside_click: MaybeSignal<bool>,
+    /// If set, the popover is shown whenever this signal is `true` and hidden whenever it is `false`.
+    /// But note that the popover can also be shown or hidden by user interaction and if `on_show`/`on_hidden`
+    /// don't update the `open` signal, the popover can get out of sync with the signal.
+    #[prop(into, optional)]
+    open: Option<Signal<bool>>,
+    /// Callback that is called when the popover starts showing.
+    #[prop(into, optional)]
+    on_show: Option<Callback<()>>,
+    /// Callback that is called when the popover finished hiding.
+    #[prop(into, optional)]
+    on_hidden: Option<Callback<()>>,
+    /// The element(s) the popover is attached to.
+    children: Children,
+) -> impl IntoView {
+    let has_header = popover_header.is_some();
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Span> = create_node_ref();
+    let header_ref: NodeRef<Div> = create_node_ref();
+    let body_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        let (Some(element), Some(body)) = (element_ref(), body_ref()) else {
+            return;
+        };
+        let header = header_ref();
+        if has_header && header.is_none() {
+            return;
+        }
+
+        let options = JsPopoverOptions {
+            title: header
+                .map(|header| JsValue::from((*header).clone()))
+                .unwrap_or_else(|| JsValue::from_str("")),
+            content: JsValue::from((*body).clone()),
+            placement: placement().js_value().to_string(),
+            trigger: trigger().js_value().to_string(),
+            html: true,
+        };
+        let jspopover = Rc::new(JsPopover::new(
+            &element,
+            serde_wasm_bindgen::to_value(&options).unwrap(),
+        ));
+
+        let on_show = on_show.clone();
+        let on_show: Closure<dyn FnMut()> = Closure::new(move || {
+            if let Some(on_show) = &on_show {
+                on_show(());
+            }
+        });
+        let on_hidden = on_hidden.clone();
+        let on_hidden: Closure<dyn FnMut()> = Closure::new(move || {
+            if let Some(on_hidden) = &on_hidden {
+                on_hidden(());
+            }
+        });
+        let show_listener = te_popover_add_event_listener(&element, "show.te.popover", &on_show);
+        let hidden_listener =
+            te_popover_add_event_listener(&element, "hidden.te.popover", &on_hidden);
+
+        if let Some(open) = open {
+            let jspopover = Rc::clone(&jspopover);
+            create_effect(move |_| {
+                if open() {
+                    jspopover.show();
+                } else {
+                    jspopover.hide();
+                }
+            });
+        }
+
+        {
+            let jspopover = Rc::clone(&jspopover);
+            let element = element.clone();
+            let body = body.clone();
+            // This is checked on each click, so changing it doesn't re-create the popover and hide it
+            let handle = window_event_listener(ev::click, move |ev| {
+                if !dismiss_on_outside_click.get_untracked() {
+                    return;
+                }
+                let Some(target) = ev.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok())
+                else {
+                    return;
+                };
+                let inside_header = header_ref
+                    .get_untracked()
+                    .map(|header| header.contains(Some(&target)))
+                    .unwrap_or(false);
+                if !element.contains(Some(&target))
+                    && !body.contains(Some(&target))
+                    && !inside_header
+                {
+                    jspopover.hide();
+                }
+            });
+            on_cleanup(move || handle.remove());
+        }
+
+        on_cleanup(move || {
+            te_popover_remove_event_listener(&element, "show.te.popover", &show_listener);
+            te_popover_remove_event_listener(&element, "hidden.te.popover", &hidden_listener);
+            jspopover.dispose();
+            std::mem::drop(on_show);
+            std::mem::drop(on_hidden);
+        });
+    });
+
+    view! {
+        <span ref=element_ref class="inline-block">
+            {children()}
+        </span>
+        // This content is moved into the popover by Tailwind Elements when the popover is shown.
+        <div class="hidden">
+            {popover_header.map(|header| view! {
+                <div ref=header_ref>{(header.children)()}</div>
+            })}
+            <div ref=body_ref>{(popover_body.children)()}</div>
+        </div>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Popover)]
+    type JsPopover;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Popover, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsPopover;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Popover, final)]
+    fn show(this: &JsPopover);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Popover, final)]
+    fn hide(this: &JsPopover);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Popover, final)]
+    fn dispose(this: &JsPopover);
+}
+
+#[wasm_bindgen(inline_js = "
+    export function te_popover_add_event_listener(popover_html_elem, event_name, callback) {
+        const listener = (event) => {
+            callback();
+        };
+        popover_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_popover_remove_event_listener(popover_html_elem, event_name, listener) {
+        popover_html_elem.removeEventListener(event_name, listener);
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_popover_add_event_listener(
+        popover: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_popover_remove_event_listener(
+        popover: &web_sys::HtmlElement,
+        event_name: &str,
+        listener: &JsValue,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsPopoverOptions {
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    title: JsValue,
+
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    content: JsValue,
+
+    placement: String,
+
+    trigger: String,
+
+    html: bool,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/components/popover/#docsTabsAPI
+}
@@ -0,0 +1,366 @@
+use leptos::{html::Ul, *};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+/// How the steps of a [Stepper] are laid out.
+///
+/// See [Tailwind Elements: Stepper](https://tailwind-elements.com/docs/standard/components/stepper/)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepperMode {
+    /// Step headers are shown in a row and the content of the active step slides in below them
+    #[default]
+    Horizontal,
+    /// Step headers are shown in a column and the content of the active step expands below its header
+    Vertical,
+    /// Step headers are hidden and a compact bar with back/next buttons is shown below the content
+    Mobile,
+}
+
+impl StepperMode {
+    const fn js_value(self) -> &'static str {
+        match self {
+            Self::Horizontal | Self::Mobile => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+/// Options for a [Stepper].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StepperOptions {
+    /// How the steps are laid out. See [StepperMode].
+    pub mode: StepperMode,
+
+    /// If `true`, the user can only move forward once the `validate` callbacks of the current step
+    /// and all steps in between return `true`. Moving backward is always possible.
+    pub linear: bool,
+}
+
+/// A Stepper component, i.e. a multi-step form or wizard.
+///
+/// See [Tailwind Elements: Stepper](https://tailwind-elements.com/docs/standard/components/stepper/)
+#[derive(Clone, Copy)]
+pub struct Stepper {
+    stepper_impl: StoredValue<Rc<StepperImpl>>,
+}
+
+impl Stepper {
+    /// Create a new stepper. This returns a handle to navigate between steps and a view that
+    /// needs to be added to the page. `content` is called with the handle and should return
+    /// the [Step]s of the stepper.
+    pub fn new<ContentFn, ContentView>(
+        options: StepperOptions,
+        content: ContentFn,
+    ) -> (Self, impl IntoView)
+    where
+        ContentFn: Fn(Self) -> ContentView + 'static,
+        ContentView: IntoView,
+    {
+        let stepper_impl = Rc::new(StepperImpl {
+            jsstepper: RefCell::new(None),
+            options,
+            active_step: create_rw_signal(0),
+            validators: RefCell::new(vec![]),
+        });
+        let stepper_impl = store_value(stepper_impl);
+        let stepper = Self { stepper_impl };
+        let view = view! {
+            <StepperView stepper=stepper content=content />
+        };
+        (stepper, view)
+    }
+
+    /// Go to the next step. In linear mode, this only succeeds if the current step is valid.
+    pub fn next(&self) {
+        self.to(self.active_step().get_untracked() + 1);
+    }
+
+    /// Go to the previous step.
+    pub fn prev(&self) {
+        if let Some(step) = self.active_step().get_untracked().checked_sub(1) {
+            self.to(step);
+        }
+    }
+
+    /// Go to the step with the given (zero based) index. Indices past the last step are ignored.
+    ///
+    /// In linear mode, moving forward stops at the first step whose `validate` callback returns `false`.
+    pub fn to(&self, step: usize) {
+        self.stepper_impl.with_value(|s| s.to(step));
+    }
+
+    /// A signal with the (zero based) index of the currently active step.
+    pub fn active_step(&self) -> Signal<usize> {
+        self.stepper_impl.with_value(|s| s.active_step.into())
+    }
+
+    /// The number of steps in this stepper.
+    pub fn num_steps(&self) -> usize {
+        self.stepper_impl
+            .with_value(|s| s.validators.borrow().len())
+    }
+
+    fn register_step(&self, validate: Option<Callback<(), bool>>) -> usize {
+        self.stepper_impl.with_value(|s| {
+            let mut validators = s.validators.borrow_mut();
+            validators.push(validate);
+            validators.len() - 1
+        })
+    }
+
+    fn mode(&self) -> StepperMode {
+        self.stepper_impl.with_value(|s| s.options.mode)
+    }
+}
+
+struct StepperImpl {
+    jsstepper: RefCell<Option<JsStepper>>,
+    options: StepperOptions,
+    active_step: RwSignal<usize>,
+    validators: RefCell<Vec<Option<Callback<(), bool>>>>,
+}
+
+impl StepperImpl {
+    fn to(&self, step: usize) {
+        let current = self.active_step.get_untracked();
+        // Clone the validators so they can access the stepper themselves without a double borrow.
+        let validators = self.validators.borrow().clone();
+        if step >= validators.len() {
+            return;
+        }
+
+        let mut target = step;
+        if self.options.linear {
+            for (index, validate) in validators.iter().enumerate().take(step).skip(current) {
+                let valid = validate
+                    .as_ref()
+                    .map(|validate| validate(()))
+                    .unwrap_or(true);
+                if !valid {
+                    target = index;
+                    break;
+                }
+            }
+        }
+        if target == current {
+            return;
+        }
+
+        let jsstepper = self.jsstepper.borrow();
+        let Some(jsstepper) = jsstepper.as_ref() else {
+            panic!("Tried to change the step of a stepper but its view is not added to the page");
+        };
+        jsstepper.change_step(u32::try_from(target).expect("step out of bounds"));
+        self.active_step.set(target);
+    }
+}
+
+#[component]
+fn StepperView<ContentFn, ContentView>(stepper: Stepper, content: ContentFn) -> impl IntoView
+where
+    ContentFn: Fn(Stepper) -> ContentView + 'static,
+    ContentView: IntoView,
+{
+    let mode = stepper.mode();
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let stepper_ref: NodeRef<Ul> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = stepper_ref() {
+            let options = JsStepperOptions {
+                stepper_type: mode.js_value().to_string(),
+            };
+            let jsstepper =
+                JsStepper::new(&element, serde_wasm_bindgen::to_value(&options).unwrap());
+            stepper
+                .stepper_impl
+                .with_value(|s| *s.jsstepper.borrow_mut() = Some(jsstepper));
+
+            // Tailwind Elements would change the step on click or enter directly, bypassing validation
+            // and our active_step signal. Route these through Stepper::to instead.
+            let on_navigate: Closure<dyn FnMut(u32)> = Closure::new(move |step: u32| {
+                stepper.to(step as usize);
+            });
+            te_stepper_intercept_navigation(&element, &on_navigate);
+
+            // We need to copy the Rc and capture it because the stored value may already be un-stored in `on_cleanup`.
+            let stepper_impl = stepper.stepper_impl.with_value(Rc::clone);
+            on_cleanup(move || {
+                let jsstepper = stepper_impl.jsstepper.borrow_mut().take();
+                if let Some(jsstepper) = jsstepper {
+                    jsstepper.dispose();
+                }
+                std::mem::drop(on_navigate);
+            });
+        }
+    });
+    provide_context::<Stepper>(stepper);
+
+    let class = match mode {
+        StepperMode::Horizontal | StepperMode::Mobile => "relative m-0 flex list-none justify-between overflow-hidden p-0 transition-[height] duration-200 ease-in-out",
+        StepperMode::Vertical => "relative m-0 w-full list-none overflow-hidden p-0 transition-[height] duration-200 ease-in-out",
+    };
+
+    view! {
+        <ul
+            ref=stepper_ref
+            class=class
+            data-te-stepper-type=mode.js_value()
+        >
+            {content(stepper)}
+        </ul>
+        {(mode == StepperMode::Mobile).then(|| view! {
+            <MobileStepperBar stepper=stepper />
+        })}
+    }
+}
+
+#[component]
+fn MobileStepperBar(stepper: Stepper) -> impl IntoView {
+    let active_step = stepper.active_step();
+    let num_steps = stepper.num_steps();
+    view! {
+        <div class="flex items-center justify-between bg-neutral-50 px-4 py-2 dark:bg-neutral-700">
+            <button
+                type="button"
+                class="rounded px-4 py-2 text-xs font-medium uppercase text-primary hover:bg-neutral-100 disabled:opacity-50 dark:hover:bg-neutral-600"
+                disabled=move || active_step() == 0
+                on:click=move |_| stepper.prev()
+            >
+                "Back"
+            </button>
+            <span class="text-sm text-neutral-500 dark:text-neutral-300">
+                {move || format!("{} / {}", active_step() + 1, num_steps)}
+            </span>
+            <button
+                type="button"
+                class="rounded px-4 py-2 text-xs font-medium uppercase text-primary hover:bg-neutral-100 disabled:opacity-50 dark:hover:bg-neutral-600"
+                disabled=move || active_step() + 1 >= num_steps
+                on:click=move |_| stepper.next()
+            >
+                "Next"
+            </button>
+        </div>
+    }
+}
+
+/// A step in a [Stepper].
+#[component]
+pub fn Step(
+    /// The title shown in the header of the step.
+    #[prop(into)]
+    title: MaybeSignal<String>,
+    /// In linear mode, this is called before moving forward past this step. Return `false` to stay on this step.
+    #[prop(into, optional)]
+    validate: Option<Callback<(), bool>>,
+    /// Content shown while the step is active
+    children: Children,
+) -> impl IntoView {
+    let stepper = use_context::<Stepper>().expect("Step must be used inside a Stepper");
+    let index = stepper.register_step(validate);
+    let mode = stepper.mode();
+
+    let (step_class, head_class, icon_class, text_class, content_class) = match mode {
+        StepperMode::Horizontal | StepperMode::Mobile => (
+            "w-[4.5rem] flex-auto",
+            "flex cursor-pointer items-center pl-2 leading-[1.3rem] no-underline after:ml-2 after:h-px after:w-full after:flex-1 after:bg-[#e0e0e0] after:content-[''] focus:outline-none dark:after:bg-neutral-600 dark:hover:bg-[#3b3b3b]",
+            "my-6 mr-2 flex h-[1.938rem] w-[1.938rem] items-center justify-center rounded-full bg-[#ebedef] text-sm font-medium text-[#40464f]",
+            "font-medium text-neutral-500 after:flex after:text-[0.8rem] after:content-[data-content] dark:text-neutral-300",
+            "absolute left-0 w-full p-4 transition-all duration-500 ease-in-out",
+        ),
+        StepperMode::Vertical => (
+            "relative h-fit after:absolute after:left-[2.45rem] after:top-[3.6rem] after:mt-px after:h-[calc(100%-2.45rem)] after:w-px after:bg-[#e0e0e0] after:content-[''] dark:after:bg-neutral-600",
+            "flex cursor-pointer items-center p-6 leading-[1.3rem] no-underline after:bg-[#e0e0e0] after:content-[''] hover:bg-[#f9f9f9] focus:outline-none dark:after:bg-neutral-600 dark:hover:bg-[#3b3b3b]",
+            "mr-3 flex h-[1.938rem] w-[1.938rem] items-center justify-center rounded-full bg-[#ebedef] text-sm font-medium text-[#40464f]",
+            "text-neutral-500 after:absolute after:flex after:text-[0.8rem] after:content-[data-content] dark:text-neutral-300",
+            "transition-[height, margin-bottom, padding-top, padding-bottom] left-0 overflow-hidden pb-6 pl-[3.75rem] pr-6 duration-300 ease-in-out",
+        ),
+    };
+    let head_class = if mode == StepperMode::Mobile {
+        format!("{head_class} hidden")
+    } else {
+        head_class.to_string()
+    };
+    // Only the initial state is rendered here. Afterwards, Tailwind Elements takes care of the classes.
+    let content_class = if mode != StepperMode::Vertical && index > 0 {
+        format!("{content_class} translate-x-[150%]")
+    } else {
+        content_class.to_string()
+    };
+
+    view! {
+        <li data-te-stepper-step-ref class=step_class>
+            <div data-te-stepper-head-ref class=head_class tabindex="-1">
+                <span data-te-stepper-head-icon-ref class=icon_class>
+                    {index + 1}
+                </span>
+                <span data-te-stepper-head-text-ref class=text_class>
+                    {title}
+                </span>
+            </div>
+            <div data-te-stepper-content-ref class=content_class>
+                {children()}
+            </div>
+        </li>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Stepper)]
+    type JsStepper;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Stepper, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsStepper;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Stepper, js_name = changeStep, final)]
+    fn change_step(this: &JsStepper, index: u32);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Stepper, final)]
+    fn dispose(this: &JsStepper);
+}
+
+#[wasm_bindgen(
+    inline_js = "export function te_stepper_intercept_navigation(stepper_html_elem, callback) {
+        const intercept = (event) => {
+            const head = event.target.closest('[data-te-stepper-head-ref]');
+            if (head === null || !stepper_html_elem.contains(head)) {
+                return;
+            }
+            const steps = Array.from(stepper_html_elem.querySelectorAll('[data-te-stepper-step-ref]'));
+            const index = steps.indexOf(head.closest('[data-te-stepper-step-ref]'));
+            if (index >= 0) {
+                // Capturing on the stepper element and stopping propagation keeps the event from reaching the Tailwind Elements handlers on the step head.
+                event.preventDefault();
+                event.stopPropagation();
+                callback(index);
+            }
+        };
+        stepper_html_elem.addEventListener('mousedown', intercept, true);
+        stepper_html_elem.addEventListener('keydown', (event) => {
+            if (event.key === 'Enter' || event.key === ' ') {
+                intercept(event);
+            }
+        }, true);
+    }"
+)]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_stepper_intercept_navigation(
+        stepper: &web_sys::HtmlElement,
+        callback: &Closure<dyn FnMut(u32)>,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsStepperOptions {
+    stepper_type: String,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/components/stepper/#docsTabsAPI
+}
@@ -0,0 +1,342 @@
+use leptos::{html::Div, *};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::{
+    closure::Closure,
+    prelude::{wasm_bindgen, JsValue},
+};
+use web_sys::HtmlElement;
+
+use super::AlertType;
+
+/// The corner of the screen in which a [ToastProvider] stacks its toasts.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastPosition {
+    /// Top left corner
+    TopLeft,
+    /// Top edge, horizontally centered
+    TopCenter,
+    /// Top right corner
+    #[default]
+    TopRight,
+    /// Bottom left corner
+    BottomLeft,
+    /// Bottom edge, horizontally centered
+    BottomCenter,
+    /// Bottom right corner
+    BottomRight,
+}
+
+impl ToastPosition {
+    const fn class(self) -> &'static str {
+        match self {
+            Self::TopLeft => "top-0 left-0",
+            Self::TopCenter => "top-0 left-1/2 -translate-x-1/2",
+            Self::TopRight => "top-0 right-0",
+            Self::BottomLeft => "bottom-0 left-0 flex-col-reverse",
+            Self::BottomCenter => "bottom-0 left-1/2 -translate-x-1/2 flex-col-reverse",
+            Self::BottomRight => "bottom-0 right-0 flex-col-reverse",
+        }
+    }
+}
+
+/// Describes a toast to be shown by [Toaster::push].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToastSpec {
+    /// The color variant of the toast
+    pub kind: AlertType,
+
+    /// The title shown in the header of the toast
+    pub title: String,
+
+    /// The message shown in the body of the toast
+    pub body: String,
+
+    /// Whether the toast hides automatically after `delay`. If `false`, the toast stays until the user closes it.
+    pub autohide: bool,
+
+    /// How long the toast is shown before it hides automatically. Only used if `autohide` is `true`.
+    pub delay: Duration,
+}
+
+impl Default for ToastSpec {
+    fn default() -> Self {
+        Self {
+            kind: AlertType::Primary,
+            title: String::new(),
+            body: String::new(),
+            autohide: true,
+            delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Identifies a toast that was shown with [Toaster::push].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+/// Handle to show toasts from anywhere below a [ToastProvider]. Get it with [use_toaster].
+#[derive(Clone, Copy)]
+pub struct Toaster {
+    toasts: RwSignal<Vec<(ToastId, ToastSpec)>>,
+    dismissed: RwSignal<HashSet<ToastId>>,
+    next_id: StoredValue<u64>,
+}
+
+impl Toaster {
+    /// Show a new toast. It is stacked below (or, for bottom positions, above) the toasts already showing.
+    pub fn push(&self, spec: ToastSpec) -> ToastId {
+        let id = ToastId(self.next_id.get_value());
+        self.next_id.set_value(id.0 + 1);
+        self.toasts.update(|toasts| toasts.push((id, spec)));
+        id
+    }
+
+    /// Hide a toast that was shown with [Toaster::push]. This does nothing if the toast is already hidden.
+    pub fn dismiss(&self, id: ToastId) {
+        self.dismissed.update(|dismissed| {
+            dismissed.insert(id);
+        });
+    }
+
+    fn remove(&self, id: ToastId) {
+        self.toasts
+            .update(|toasts| toasts.retain(|(toast_id, _)| *toast_id != id));
+        self.dismissed.update(|dismissed| {
+            dismissed.remove(&id);
+        });
+    }
+}
+
+/// Get the [Toaster] provided by the closest [ToastProvider] ancestor.
+///
+/// Panics if there is no [ToastProvider] ancestor.
+pub fn use_toaster() -> Toaster {
+    use_context::<Toaster>().expect("Expected a ToastProvider ancestor")
+}
+
+/// Provides a [Toaster] to all its children and renders the toasts pushed to it.
+#[component]
+pub fn ToastProvider(
+    /// The corner of the screen in which the toasts are stacked. See [ToastPosition].
+    #[prop(default = ToastPosition::TopRight)]
+    position: ToastPosition,
+    /// Callback that is called with the id of a toast once it is closed, either by the user,
+    /// by hiding automatically, or by [Toaster::dismiss].
+    #[prop(into, optional)]
+    on_close: Option<Callback<ToastId>>,
+    children: Children,
+) -> impl IntoView {
+    let toaster = Toaster {
+        toasts: create_rw_signal(vec![]),
+        dismissed: create_rw_signal(HashSet::new()),
+        next_id: store_value(0),
+    };
+    provide_context(toaster);
+
+    let class = format!(
+        "pointer-events-none fixed z-[1060] flex w-96 max-w-full flex-col gap-2 p-4 {}",
+        position.class()
+    );
+
+    view! {
+        {children()}
+        <div class=class>
+            <For each=move || toaster.toasts.get() key=|(id, _)| *id let:toast>{
+                let (id, spec) = toast;
+                let on_close = on_close.clone();
+                view! {
+                    <Toast
+                        kind=spec.kind
+                        title=spec.title
+                        autohide=spec.autohide
+                        delay=spec.delay
+                        open=Signal::derive(move || !toaster.dismissed.with(|dismissed| dismissed.contains(&id)))
+                        on_hidden=Callback::new(move |_| {
+                            // Removing the toast disposes it, so don't do that while Tailwind Elements is still running its event handler.
+                            set_timeout(move || toaster.remove(id), Duration::ZERO);
+                            if let Some(on_close) = &on_close {
+                                on_close(id);
+                            }
+                        })
+                    >
+                        {spec.body}
+                    </Toast>
+                }
+            }</For>
+        </div>
+    }
+}
+
+/// A Toast component.
+///
+/// To show toasts from anywhere in your app without placing them manually, see [ToastProvider].
+///
+/// See [Tailwind Elements: Toast](https://tailwind-elements.com/docs/standard/components/toast/)
+#[component]
+pub fn Toast(
+    /// The color variant of the toast. See [AlertType].
+    #[prop(default = AlertType::Primary)]
+    kind: AlertType,
+    /// The title shown in the header of the toast.
+    #[prop(into)]
+    title: MaybeSignal<String>,
+    /// Whether the toast hides automatically after `delay`.
+    #[prop(into, default = true.into())]
+    autohide: MaybeSignal<bool>,
+    /// How long the toast is shown before it hides automatically.
+    #[prop(into, default = Duration::from_secs(5).into())]
+    delay: MaybeSignal<Duration>,
+    /// The toast is shown whenever this signal becomes `true` and hidden whenever it becomes `false`.
+    /// But note that the toast can also hide automatically or be closed by the user and if `on_hidden`
+    /// doesn't update the `open` signal, the toast can get out of sync with the signal.
+    #[prop(into, default = true.into())]
+    open: MaybeSignal<bool>,
+    /// Callback that is called when the toast finished hiding.
+    #[prop(into, optional)]
+    on_hidden: Option<Callback<()>>,
+    /// Content shown in the body of the toast
+    children: Children,
+) -> impl IntoView {
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Div> = create_node_ref();
+    let jstoast = store_value(None::<Rc<JsToast>>);
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let options = JsToastOptions {
+                autohide: autohide(),
+                delay: u32::try_from(delay().as_millis()).expect("delay out of bounds"),
+            };
+            let new_jstoast = Rc::new(JsToast::new(
+                &element,
+                serde_wasm_bindgen::to_value(&options).unwrap(),
+            ));
+
+            let on_hidden = on_hidden.clone();
+            let on_hidden: Closure<dyn FnMut()> = Closure::new(move || {
+                if let Some(on_hidden) = &on_hidden {
+                    on_hidden(());
+                }
+            });
+            let hidden_listener =
+                te_toast_add_event_listener(&element, "hidden.te.toast", &on_hidden);
+
+            {
+                let new_jstoast = Rc::clone(&new_jstoast);
+                create_effect(move |_| {
+                    if open() {
+                        new_jstoast.show();
+                    } else {
+                        new_jstoast.hide();
+                    }
+                });
+            }
+
+            jstoast.set_value(Some(Rc::clone(&new_jstoast)));
+            on_cleanup(move || {
+                te_toast_remove_event_listener(&element, "hidden.te.toast", &hidden_listener);
+                new_jstoast.dispose();
+                std::mem::drop(on_hidden);
+            });
+        }
+    });
+
+    let hide = move |_| {
+        jstoast.with_value(|jstoast| {
+            if let Some(jstoast) = jstoast {
+                jstoast.hide();
+            }
+        })
+    };
+
+    view! {
+        <div
+            ref=element_ref
+            class=format!("pointer-events-auto hidden w-full rounded-lg bg-clip-padding text-sm shadow-lg shadow-black/5 data-[te-toast-show]:block data-[te-toast-hide]:hidden {}", kind.class())
+            role="alert"
+            aria-live="assertive"
+            aria-atomic="true"
+        >
+            <div class="flex items-center justify-between rounded-t-lg border-b-2 border-black/10 bg-clip-padding px-4 pb-2 pt-2.5">
+                <p class="font-bold">{title}</p>
+                <button
+                    type="button"
+                    class="ml-2 box-content rounded-none border-none opacity-80 hover:no-underline hover:opacity-75 focus:opacity-100 focus:shadow-none focus:outline-none"
+                    aria-label="Close"
+                    on:click=hide
+                >
+                    <svg
+                        xmlns="http://www.w3.org/2000/svg"
+                        fill="none"
+                        viewBox="0 0 24 24"
+                        stroke-width="1.5"
+                        stroke="currentColor"
+                        class="h-6 w-6">
+                        <path
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                            d="M6 18L18 6M6 6l12 12" />
+                    </svg>
+                </button>
+            </div>
+            <div class="break-words rounded-b-lg px-4 py-4">
+                {children()}
+            </div>
+        </div>
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Toast)]
+    type JsToast;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Toast, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsToast;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Toast, final)]
+    fn show(this: &JsToast);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Toast, final)]
+    fn hide(this: &JsToast);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Toast, final)]
+    fn dispose(this: &JsToast);
+}
+
+#[wasm_bindgen(inline_js = "
+    export function te_toast_add_event_listener(toast_html_elem, event_name, callback) {
+        const listener = (event) => {
+            callback();
+        };
+        toast_html_elem.addEventListener(event_name, listener);
+        return listener;
+    }
+    export function te_toast_remove_event_listener(toast_html_elem, event_name, listener) {
+        toast_html_elem.removeEventListener(event_name, listener);
+    }
+")]
+extern "C" {
+    #[wasm_bindgen]
+    fn te_toast_add_event_listener(
+        toast: &web_sys::HtmlElement,
+        event_name: &str,
+        callback: &Closure<dyn FnMut()>,
+    ) -> JsValue;
+
+    #[wasm_bindgen]
+    fn te_toast_remove_event_listener(
+        toast: &web_sys::HtmlElement,
+        event_name: &str,
+        listener: &JsValue,
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsToastOptions {
+    autohide: bool,
+
+    delay: u32,
+}
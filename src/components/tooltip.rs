@@ -0,0 +1,246 @@
+use leptos::{
+    html::{Div, Span},
+    *,
+};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen::prelude::{wasm_bindgen, JsValue};
+use web_sys::HtmlElement;
+
+/// Where a [Tooltip] is shown relative to the element it is attached to.
+///
+/// See [Tailwind Elements: Tooltip#Placement](https://tailwind-elements.com/docs/standard/components/tooltip/#placement)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TooltipPlacement {
+    /// Show the tooltip above the element.
+    #[default]
+    Top,
+    /// Show the tooltip to the right of the element.
+    Right,
+    /// Show the tooltip below the element.
+    Bottom,
+    /// Show the tooltip to the left of the element.
+    Left,
+    /// Let Tailwind Elements choose the placement with the most available space.
+    Auto,
+}
+
+impl TooltipPlacement {
+    pub(crate) const fn js_value(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Right => "right",
+            Self::Bottom => "bottom",
+            Self::Left => "left",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+/// What user interaction shows and hides a [Tooltip].
+///
+/// See [Tailwind Elements: Tooltip#Options](https://tailwind-elements.com/docs/standard/components/tooltip/#docsTabsAPI)
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TooltipTrigger {
+    /// Show the tooltip while the element is hovered.
+    Hover,
+    /// Show the tooltip while the element has focus.
+    Focus,
+    /// Show the tooltip while the element is hovered or has focus.
+    #[default]
+    HoverFocus,
+    /// Toggle the tooltip when the element is clicked.
+    Click,
+    /// Never show the tooltip based on user interaction. Use the `open` property to show or hide it.
+    Manual,
+}
+
+impl TooltipTrigger {
+    pub(crate) const fn js_value(self) -> &'static str {
+        match self {
+            Self::Hover => "hover",
+            Self::Focus => "focus",
+            Self::HoverFocus => "hover focus",
+            Self::Click => "click",
+            Self::Manual => "manual",
+        }
+    }
+}
+
+/// The content of a [Tooltip].
+#[derive(Clone)]
+pub enum TooltipTitle {
+    /// A plain text title. If the tooltip is in `html` mode, the text is interpreted as (sanitized) HTML.
+    Text(MaybeSignal<String>),
+    /// A title rendered from a [View]. This implies `html` mode.
+    View(Box<View>),
+}
+
+impl Default for TooltipTitle {
+    fn default() -> Self {
+        Self::Text(MaybeSignal::default())
+    }
+}
+
+impl From<&str> for TooltipTitle {
+    fn from(title: &str) -> Self {
+        Self::Text(title.to_string().into())
+    }
+}
+
+impl From<String> for TooltipTitle {
+    fn from(title: String) -> Self {
+        Self::Text(title.into())
+    }
+}
+
+impl From<MaybeSignal<String>> for TooltipTitle {
+    fn from(title: MaybeSignal<String>) -> Self {
+        Self::Text(title)
+    }
+}
+
+impl From<Signal<String>> for TooltipTitle {
+    fn from(title: Signal<String>) -> Self {
+        Self::Text(title.into())
+    }
+}
+
+impl From<ReadSignal<String>> for TooltipTitle {
+    fn from(title: ReadSignal<String>) -> Self {
+        Self::Text(title.into())
+    }
+}
+
+impl From<RwSignal<String>> for TooltipTitle {
+    fn from(title: RwSignal<String>) -> Self {
+        Self::Text(title.into())
+    }
+}
+
+impl From<View> for TooltipTitle {
+    fn from(title: View) -> Self {
+        Self::View(Box::new(title))
+    }
+}
+
+/// A Tooltip component. It attaches a tooltip to its children.
+///
+/// See [Tailwind Elements: Tooltip](https://tailwind-elements.com/docs/standard/components/tooltip/)
+#[component]
+pub fn Tooltip(
+    /// The content of the tooltip. See [TooltipTitle].
+    #[prop(into)]
+    title: TooltipTitle,
+    /// Where the tooltip is shown relative to the children. See [TooltipPlacement].
+    #[prop(into, default = TooltipPlacement::Top.into())]
+    placement: MaybeSignal<TooltipPlacement>,
+    /// What user interaction shows the tooltip. See [TooltipTrigger].
+    #[prop(into, default = TooltipTrigger::HoverFocus.into())]
+    trigger: MaybeSignal<TooltipTrigger>,
+    /// Delay before showing and hiding the tooltip.
+    #[prop(into, default = Duration::ZERO.into())]
+    delay: MaybeSignal<Duration>,
+    /// Whether a text title is interpreted as HTML.
+    #[prop(into, default = false.into())]
+    html: MaybeSignal<bool>,
+    /// If set, the tooltip is shown whenever this signal is `true` and hidden whenever it is `false`.
+    /// This is mostly useful together with [TooltipTrigger::Manual].
+    #[prop(into, optional)]
+    open: Option<Signal<bool>>,
+    /// The element(s) the tooltip is attached to.
+    children: Children,
+) -> impl IntoView {
+    let (text_title, view_title) = match title {
+        TooltipTitle::Text(text) => (Some(text), None),
+        TooltipTitle::View(view) => (None, Some(view)),
+    };
+    let has_view_title = view_title.is_some();
+
+    // TODO This explicit initialization is a workaround for https://github.com/mdbootstrap/Tailwind-Elements/issues/1743
+    let element_ref: NodeRef<Span> = create_node_ref();
+    let view_title_ref: NodeRef<Div> = create_node_ref();
+    create_effect(move |_| {
+        if let Some(element) = element_ref() {
+            let title = match view_title_ref() {
+                Some(view_title) if has_view_title => JsValue::from((*view_title).clone()),
+                _ => JsValue::from_str(""),
+            };
+            let options = JsTooltipOptions {
+                title,
+                placement: placement().js_value().to_string(),
+                trigger: trigger().js_value().to_string(),
+                delay: u32::try_from(delay().as_millis()).expect("delay out of bounds"),
+                html: has_view_title || html(),
+            };
+            let jstooltip = Rc::new(JsTooltip::new(
+                &element,
+                serde_wasm_bindgen::to_value(&options).unwrap(),
+            ));
+
+            if let Some(open) = open {
+                let jstooltip = Rc::clone(&jstooltip);
+                create_effect(move |_| {
+                    if open() {
+                        jstooltip.show();
+                    } else {
+                        jstooltip.hide();
+                    }
+                });
+            }
+
+            on_cleanup(move || jstooltip.dispose());
+        }
+    });
+
+    view! {
+        <span
+            ref=element_ref
+            class="inline-block"
+            // Tailwind Elements reads the title from this attribute every time the tooltip is shown, so text titles stay reactive.
+            data-te-original-title=move || text_title.as_ref().map(MaybeSignal::get)
+        >
+            {children()}
+        </span>
+        {view_title.map(|view_title| view! {
+            // This content is moved into the tooltip by Tailwind Elements when the tooltip is shown.
+            <div class="hidden">
+                <div ref=view_title_ref>{view_title}</div>
+            </div>
+        })}
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = te, js_name = Tooltip)]
+    type JsTooltip;
+
+    #[wasm_bindgen(constructor, js_namespace = te, js_class = Tooltip, final)]
+    fn new(e: &HtmlElement, options: JsValue) -> JsTooltip;
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Tooltip, final)]
+    fn show(this: &JsTooltip);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Tooltip, final)]
+    fn hide(this: &JsTooltip);
+
+    #[wasm_bindgen(method, js_namespace = te, js_class = Tooltip, final)]
+    fn dispose(this: &JsTooltip);
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsTooltipOptions {
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    title: JsValue,
+
+    placement: String,
+
+    trigger: String,
+
+    delay: u32,
+
+    html: bool,
+    // TODO There are more options, see https://tailwind-elements.com/docs/standard/components/tooltip/#docsTabsAPI
+}
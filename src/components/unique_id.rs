@@ -0,0 +1,17 @@
+use leptos::leptos_dom::HydrationCtx;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Generate an id for an element that is unique on the page, e.g. `te-dropdown-1-0-3`.
+///
+/// The id is derived from the hydration key of the component, so the server and the client generate
+/// the same id and it survives hydration.
+pub(crate) fn unique_id(prefix: &str) -> String {
+    match HydrationCtx::id() {
+        Some(key) => format!("{prefix}-{key}"),
+        None => {
+            // Only happens for islands that aren't hydrated
+            static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+            format!("{prefix}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed))
+        }
+    }
+}
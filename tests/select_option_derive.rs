@@ -0,0 +1,42 @@
+use leptos_twelements::components::forms::SelectOption;
+
+#[derive(Clone, Debug, PartialEq, SelectOption)]
+enum Country {
+    #[select_option(value = "de", label = "Germany", group = "Europe")]
+    Germany,
+    #[select_option(value = "fr", group = "Europe", disabled)]
+    France,
+    #[select_option(label = "United States", group = "America")]
+    UnitedStates,
+    Antarctica,
+}
+
+#[test]
+fn value() {
+    assert_eq!("de", Country::Germany.value());
+    assert_eq!("fr", Country::France.value());
+    assert_eq!("UnitedStates", Country::UnitedStates.value());
+    assert_eq!("Antarctica", Country::Antarctica.value());
+}
+
+#[test]
+fn group() {
+    assert_eq!(Some("Europe".to_string()), Country::Germany.group());
+    assert_eq!(Some("Europe".to_string()), Country::France.group());
+    assert_eq!(Some("America".to_string()), Country::UnitedStates.group());
+    assert_eq!(None, Country::Antarctica.group());
+}
+
+#[test]
+fn disabled() {
+    assert!(!Country::Germany.disabled());
+    assert!(Country::France.disabled());
+    assert!(!Country::UnitedStates.disabled());
+    assert!(!Country::Antarctica.disabled());
+}
+
+#[test]
+fn defaults() {
+    assert_eq!(None, Country::Germany.secondary_text());
+    assert_eq!(None, Country::Germany.icon());
+}